@@ -254,6 +254,9 @@ mod libmruby {
             .include(buildpath::source::mruby_sys_ext_include_dir())
             .define("MRB_DISABLE_STDIO", None)
             .define("MRB_UTF8_STRING", None)
+            // Compile in `mrb->code_fetch_hook`, which backs the step and
+            // stack-depth budgets in `eval_limited`.
+            .define("MRB_ENABLE_DEBUG_HOOK", None)
             .define(mrb_int, None)
             .define("DISABLE_GEMS", None)
             .define("ARTICHOKE", None);
@@ -294,6 +297,9 @@ mod libmruby {
             ))
             .clang_arg("-DMRB_DISABLE_STDIO")
             .clang_arg("-DMRB_UTF8_STRING")
+            // Must match the C build so the generated `mrb_state` layout
+            // includes the debug hook fields.
+            .clang_arg("-DMRB_ENABLE_DEBUG_HOOK")
             .clang_arg(format!("-D{}", mrb_int))
             .whitelist_function("^mrb.*")
             .whitelist_type("^mrb.*")
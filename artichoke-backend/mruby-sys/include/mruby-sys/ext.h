@@ -92,6 +92,25 @@ MRB_API mrb_value mrb_sys_proc_value(struct mrb_state *mrb, struct RProc *proc);
 
 MRB_API mrb_value mrb_sys_new_symbol(mrb_sym id);
 
+// Define methods and inspect the call stack
+
+/**
+ * Define a method on `klass` whose body is the given proc.
+ *
+ * The proc may be a cfunc proc with an environment, which allows native
+ * method definitions to stash state — for example the visibility metadata
+ * used to enforce private and protected dispatch.
+ */
+MRB_API void mrb_sys_define_method_from_proc(mrb_state *mrb,
+                                             struct RClass *klass, mrb_sym mid,
+                                             struct RProc *proc);
+
+/**
+ * Return the `self` of the frame that invoked the current method, or `nil`
+ * when the current frame has no caller.
+ */
+MRB_API mrb_value mrb_sys_caller_self(mrb_state *mrb);
+
 // Manage Rust-backed `mrb_value`s
 
 MRB_API void mrb_sys_set_instance_tt(struct RClass *class, enum mrb_vtype type);
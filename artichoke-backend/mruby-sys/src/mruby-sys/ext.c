@@ -152,6 +152,26 @@ MRB_API mrb_value mrb_sys_new_symbol(mrb_sym id) {
   return value;
 }
 
+// Define methods and inspect the call stack
+
+MRB_API void mrb_sys_define_method_from_proc(mrb_state *mrb,
+                                             struct RClass *klass, mrb_sym mid,
+                                             struct RProc *proc) {
+  mrb_method_t method;
+
+  MRB_METHOD_FROM_PROC(method, proc);
+  mrb_define_method_raw(mrb, klass, mid, method);
+}
+
+MRB_API mrb_value mrb_sys_caller_self(mrb_state *mrb) {
+  mrb_callinfo *ci = mrb->c->ci;
+
+  if (ci > mrb->c->cibase && ci->stackent != NULL) {
+    return ci->stackent[0];
+  }
+  return mrb_nil_value();
+}
+
 // Manage Rust-backed `mrb_value`s
 
 MRB_API void mrb_sys_set_instance_tt(struct RClass *class,
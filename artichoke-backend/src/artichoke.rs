@@ -1,16 +1,37 @@
-use std::ffi::c_void;
+use std::ffi::{c_void, CString};
 use std::ops::{Deref, DerefMut};
 use std::ptr::NonNull;
 
+use crate::core::Value as _;
+use crate::exception::RubyException;
 use crate::ffi::{self, InterpreterExtractError};
+use crate::fs::LoadPathResolver;
+use crate::state::output::Output;
 use crate::state::State;
 use crate::sys;
+use crate::value::Value;
 
 /// Interpreter instance.
 ///
 /// Functionality is added to the interpreter via traits, for example,
 /// [garbage collection](crate::gc::MrbGarbageCollection) or
 /// [eval](crate::core::Eval).
+///
+/// # Thread safety
+///
+/// `Artichoke` is neither [`Send`] nor [`Sync`]. The interpreter owns raw
+/// pointers into the mruby VM, which has no internal synchronization, so an
+/// interpreter must only ever be accessed from one thread at a time.
+///
+/// ```compile_fail
+/// fn assert_send<T: Send>() {}
+/// assert_send::<artichoke_backend::Artichoke>();
+/// ```
+///
+/// To move an interpreter to another thread, hand it off with
+/// [`Artichoke::into_sendable`], which consumes the interpreter and yields a
+/// [`SendableArtichoke`] token that can cross the thread boundary and be
+/// reactivated on the other side.
 #[derive(Debug)]
 pub struct Artichoke {
     /// Underlying mruby interpreter.
@@ -114,8 +135,118 @@ impl Artichoke {
         guard.interp().mrb.as_ptr()
     }
 
+    /// Consume an interpreter and return a [`Send`] token for moving it to
+    /// another thread.
+    ///
+    /// The [`State`] is serialized into the `mrb` userdata pointer, the same
+    /// dormant representation used when crossing an FFI boundary, so no
+    /// interpreter internals remain accessible on the current thread. Call
+    /// [`SendableArtichoke::activate`] on the receiving thread to reconstitute
+    /// the interpreter.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state has already been moved into the `mrb` userdata
+    /// pointer, an [`InterpreterExtractError`] is returned.
+    pub fn into_sendable(mut self) -> Result<SendableArtichoke, InterpreterExtractError> {
+        let state = self.state.take().ok_or(InterpreterExtractError)?;
+        unsafe {
+            let mrb = self.mrb.as_ptr();
+            (*mrb).ud = Box::into_raw(state) as *mut c_void;
+        }
+        Ok(SendableArtichoke(self.mrb))
+    }
+
+    /// Report whether a top-level class or module is defined in this build.
+    ///
+    /// Artichoke builds are configurable with Cargo features, so embedders
+    /// cannot assume every core constant is present. This capability query
+    /// lets embedders probe for `Enumerable`, `Comparable`, `Math`, and
+    /// friends at runtime and degrade gracefully when a feature was compiled
+    /// out. Stdlib constants like `SecureRandom` only report as supported
+    /// once their library has been required.
+    ///
+    /// Names that are not valid constants, including names containing NUL
+    /// bytes, are reported as unsupported.
+    pub fn supports(&mut self, feature: &str) -> bool {
+        let feature = if let Ok(feature) = CString::new(feature) {
+            feature
+        } else {
+            return false;
+        };
+        let is_defined = unsafe {
+            self.with_ffi_boundary(|mrb| sys::mrb_class_defined(mrb, feature.as_ptr()))
+        };
+        is_defined.map_or(false, |is_defined| is_defined != 0)
+    }
+
+    /// Install a [`LoadPathResolver`] consulted by `Kernel#require`,
+    /// `Kernel#require_relative`, and `Kernel#load` before the virtual
+    /// filesystem.
+    ///
+    /// Replaces any previously installed resolver. Sources served by the
+    /// resolver participate in require-once tracking like any other source.
+    pub fn set_load_path_resolver(&mut self, resolver: Box<dyn LoadPathResolver>) {
+        if let Some(state) = self.state.as_mut() {
+            state.resolver = Some(resolver);
+        }
+    }
+
+    /// Install an entropy source that backs the `SecureRandom` module.
+    ///
+    /// `SecureRandom.random_bytes`, `hex`, `base64`, `urlsafe_base64`, and
+    /// `uuid` all draw from the installed source. When no source is
+    /// installed, `SecureRandom` draws from the OS RNG.
+    ///
+    /// This hook exists for deterministic tests and for embedding on
+    /// platforms without a system RNG. A deterministic source makes
+    /// `SecureRandom` output predictable; do not install one in production
+    /// security contexts.
+    #[cfg(feature = "stdlib-securerandom")]
+    pub fn set_secure_random_source(
+        &mut self,
+        source: Box<dyn crate::extn::stdlib::securerandom::EntropySource>,
+    ) {
+        if let Some(state) = self.state.as_mut() {
+            state.secure_random = Some(source);
+        }
+    }
+
+    /// Run registered `Kernel#at_exit` hooks in LIFO order.
+    ///
+    /// Hooks are drained as they run, so each hook runs at most once. A hook
+    /// that raises has its exception reported on the interpreter's stderr
+    /// stream and does not prevent the remaining hooks from running, matching
+    /// MRI. [`Artichoke::close`] calls this before tearing down the VM.
+    pub fn run_at_exit_hooks(&mut self) {
+        loop {
+            let hook = if let Some(state) = self.state.as_mut() {
+                state.at_exit.pop()
+            } else {
+                return;
+            };
+            let hook = if let Some(hook) = hook {
+                hook
+            } else {
+                return;
+            };
+            if let Err(exception) = Value::from(hook).funcall(self, "call", &[], None) {
+                let mut report = b"at_exit hook raised: ".to_vec();
+                report.extend_from_slice(exception.message().as_ref());
+                report.extend_from_slice(b" (");
+                report.extend_from_slice(exception.name().as_ref().as_bytes());
+                report.extend_from_slice(b")\n");
+                if let Some(state) = self.state.as_mut() {
+                    let _ = state.output.write_stderr(report);
+                }
+            }
+            let _ = unsafe { self.with_ffi_boundary(|mrb| sys::mrb_gc_unregister(mrb, hook)) };
+        }
+    }
+
     /// Consume an interpreter and free all live objects.
     pub fn close(mut self) {
+        self.run_at_exit_hooks();
         unsafe {
             let mrb = self.mrb.as_mut();
             if let Some(state) = self.state.take() {
@@ -142,6 +273,37 @@ impl Artichoke {
     }
 }
 
+/// A [`Send`] token for handing an [`Artichoke`] interpreter to another
+/// thread.
+///
+/// Created by [`Artichoke::into_sendable`]. The token holds the only handle to
+/// a dormant interpreter whose [`State`] has been serialized into the `mrb`
+/// userdata pointer; no other thread can observe interpreter internals while
+/// the token is in flight.
+#[derive(Debug)]
+pub struct SendableArtichoke(NonNull<sys::mrb_state>);
+
+// Safety:
+//
+// The token owns the only reference to the interpreter. mruby has no thread
+// affinity — the VM records no thread-local state — so moving the whole
+// interpreter, including its `State` in the `mrb` userdata pointer, to another
+// thread is sound as long as it is only accessed from one thread at a time,
+// which ownership of this token enforces.
+unsafe impl Send for SendableArtichoke {}
+
+impl SendableArtichoke {
+    /// Reconstitute the interpreter on the current thread.
+    ///
+    /// # Errors
+    ///
+    /// If the `mrb` userdata pointer does not contain a serialized [`State`],
+    /// an [`InterpreterExtractError`] is returned.
+    pub fn activate(self) -> Result<Artichoke, InterpreterExtractError> {
+        unsafe { ffi::from_user_data(self.0.as_ptr()) }
+    }
+}
+
 /// Interpreter guard that prepares an [`Artichoke`] to re-enter an FFI
 /// boundary.
 ///
@@ -221,3 +383,44 @@ impl<'a> Drop for Guard<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::test::prelude::*;
+
+    #[test]
+    fn supports_reports_present_and_absent_capabilities() {
+        let mut interp = crate::interpreter().unwrap();
+        assert!(interp.supports("Enumerable"));
+        assert!(interp.supports("Comparable"));
+        assert!(interp.supports("Kernel"));
+        assert!(!interp.supports("Ractor"));
+        assert!(!interp.supports("not a constant"));
+        assert!(!interp.supports("Enumerable\0"));
+    }
+
+    #[cfg(feature = "stdlib-securerandom")]
+    #[test]
+    fn supports_reports_stdlib_constants_after_require() {
+        let mut interp = crate::interpreter().unwrap();
+        assert!(!interp.supports("SecureRandom"));
+        let _ = interp.eval(b"require 'securerandom'").unwrap();
+        assert!(interp.supports("SecureRandom"));
+    }
+
+    #[test]
+    fn hand_off_interpreter_to_another_thread() {
+        let interp = crate::interpreter().unwrap();
+        let sendable = interp.into_sendable().unwrap();
+        let handle = thread::spawn(move || {
+            let mut interp = sendable.activate().unwrap();
+            let value = interp.eval(b"2 + 2").unwrap();
+            let result = value.try_into::<Int>(&interp).unwrap();
+            interp.close();
+            result
+        });
+        assert_eq!(handle.join().unwrap(), 4);
+    }
+}
@@ -1,16 +1,21 @@
 use std::any::Any;
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::collections::HashSet;
 use std::ffi::{CStr, CString};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::ptr::NonNull;
+use std::rc::Rc;
 
 use crate::def::{ConstantNameError, EnclosingRubyScope, Free, Method, NotDefinedError};
 use crate::exception::Exception;
+use crate::extn::core::exception::TypeError;
 use crate::ffi::InterpreterExtractError;
+use crate::types::Ruby;
 use crate::method;
 use crate::sys;
+use crate::value::Value;
 use crate::Artichoke;
 
 mod registry;
@@ -64,6 +69,31 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Set the superclass from a class `Value` resolved at runtime.
+    ///
+    /// Unlike [`Builder::with_super_class`], the superclass does not need a
+    /// registered Rust type; any class reachable from Ruby, including
+    /// user-defined classes, can be inherited from. This enables native
+    /// subclassing of classes only known by name at runtime.
+    ///
+    /// # Errors
+    ///
+    /// If `super_class` is not a `Class`, a `TypeError` is returned.
+    pub fn with_super_class_value(mut self, super_class: Value) -> Result<Self, Exception> {
+        if let Ruby::Class = super_class.ruby_type() {
+            let rclass = unsafe { sys::mrb_sys_class_ptr(super_class.inner()) };
+            let rclass = NonNull::new(rclass)
+                .ok_or_else(|| NotDefinedError::super_class(self.spec.name()))?;
+            self.super_class = Some(rclass);
+            Ok(self)
+        } else {
+            let mut message = String::from("superclass must be a Class (");
+            message.push_str(super_class.pretty_name(self.interp));
+            message.push_str(" given)");
+            Err(TypeError::from(message).into())
+        }
+    }
+
     pub fn add_method<T>(
         mut self,
         name: T,
@@ -78,6 +108,46 @@ impl<'a> Builder<'a> {
         Ok(self)
     }
 
+    pub fn add_private_method<T>(
+        mut self,
+        name: T,
+        method: Method,
+        args: sys::mrb_aspec,
+    ) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let spec = method::Spec::with_visibility(
+            method::Type::Instance,
+            name.into(),
+            method,
+            args,
+            method::Visibility::Private,
+        )?;
+        self.methods.insert(spec);
+        Ok(self)
+    }
+
+    pub fn add_protected_method<T>(
+        mut self,
+        name: T,
+        method: Method,
+        args: sys::mrb_aspec,
+    ) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let spec = method::Spec::with_visibility(
+            method::Type::Instance,
+            name.into(),
+            method,
+            args,
+            method::Visibility::Protected,
+        )?;
+        self.methods.insert(spec);
+        Ok(self)
+    }
+
     pub fn add_self_method<T>(
         mut self,
         name: T,
@@ -153,31 +223,65 @@ impl<'a> Builder<'a> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Rclass {
     name: CString,
     enclosing_scope: Option<Box<EnclosingRubyScope>>,
+    cache: Rc<Cell<Option<NonNull<sys::RClass>>>>,
+}
+
+impl PartialEq for Rclass {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.enclosing_scope == other.enclosing_scope
+    }
 }
 
+impl Eq for Rclass {}
+
 impl Rclass {
     #[must_use]
     pub fn new(name: CString, enclosing_scope: Option<Box<EnclosingRubyScope>>) -> Self {
         Self {
             name,
             enclosing_scope,
+            cache: Rc::default(),
+        }
+    }
+
+    #[must_use]
+    pub(crate) fn with_cache(
+        name: CString,
+        enclosing_scope: Option<Box<EnclosingRubyScope>>,
+        cache: Rc<Cell<Option<NonNull<sys::RClass>>>>,
+    ) -> Self {
+        Self {
+            name,
+            enclosing_scope,
+            cache,
         }
     }
 
     /// Resolve a type's [`sys::RClass`] using its enclosing scope and name.
     ///
+    /// Successful resolutions are memoized: repeated resolutions of the same
+    /// class return the cached [`NonNull`] pointer without walking the
+    /// enclosing scope or calling class lookup FFI functions. `RClass`
+    /// pointers for defined constants are stable for the life of the
+    /// interpreter because the constant table roots them in the GC. The cache
+    /// is shared with the [`Spec`] that created this `Rclass`; see
+    /// [`Spec::invalidate_rclass_cache`].
+    ///
     /// # Safety
     ///
     /// This function must be called within an [`Artichoke::with_ffi_boundary`]
     /// closure because the FFI APIs called in this function may require access
     /// to the Artichoke [`State](crate::state::State).
     pub unsafe fn resolve(&self, mrb: *mut sys::mrb_state) -> Option<NonNull<sys::RClass>> {
+        if let Some(rclass) = self.cache.get() {
+            return Some(rclass);
+        }
         let class_name = self.name.as_ptr();
-        if let Some(ref scope) = self.enclosing_scope {
+        let resolved = if let Some(ref scope) = self.enclosing_scope {
             // short circuit if enclosing scope does not exist.
             let mut scope = scope.rclass(mrb)?;
             let is_defined_under = sys::mrb_class_defined_under(mrb, scope.as_mut(), class_name);
@@ -201,7 +305,11 @@ impl Rclass {
                 let class = sys::mrb_class_get(mrb, class_name);
                 NonNull::new(class)
             }
+        };
+        if resolved.is_some() {
+            self.cache.set(resolved);
         }
+        resolved
     }
 }
 
@@ -211,6 +319,7 @@ pub struct Spec {
     cstring: CString,
     data_type: sys::mrb_data_type,
     enclosing_scope: Option<Box<EnclosingRubyScope>>,
+    resolved: Rc<Cell<Option<NonNull<sys::RClass>>>>,
 }
 
 impl Spec {
@@ -233,6 +342,7 @@ impl Spec {
                 cstring,
                 data_type,
                 enclosing_scope: enclosing_scope.map(Box::new),
+                resolved: Rc::default(),
             })
         } else {
             Err(name.into())
@@ -276,7 +386,48 @@ impl Spec {
 
     #[must_use]
     pub fn rclass(&self) -> Rclass {
-        Rclass::new(self.cstring.clone(), self.enclosing_scope.clone())
+        Rclass::with_cache(
+            self.cstring.clone(),
+            self.enclosing_scope.clone(),
+            Rc::clone(&self.resolved),
+        )
+    }
+
+    /// Resolve this spec's class constant to a live class `Value`.
+    ///
+    /// This folds the common pattern of calling [`Spec::rclass`] and
+    /// [`Rclass::resolve`] inside an [`Artichoke::with_ffi_boundary`] into a
+    /// single call. It mirrors
+    /// [`ClassRegistry::class_of`](crate::class_registry::ClassRegistry), but
+    /// is keyed by `Spec` rather than by Rust type. Returns `None` if the
+    /// class constant is not defined in the VM.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state cannot be accessed, an error is returned.
+    pub fn class_value(&self, interp: &mut Artichoke) -> Result<Option<Value>, Exception> {
+        let rclass = self.rclass();
+        let class = unsafe {
+            interp.with_ffi_boundary(|mrb| {
+                if let Some(mut rclass) = rclass.resolve(mrb) {
+                    let class = sys::mrb_sys_class_value(rclass.as_mut());
+                    Some(Value::from(class))
+                } else {
+                    None
+                }
+            })?
+        };
+        Ok(class)
+    }
+
+    /// Drop the memoized [`sys::RClass`] resolution for this spec.
+    ///
+    /// Cached `RClass` pointers remain valid while the class constant is
+    /// defined. Embedders that remove the constant out from under a
+    /// registered spec, for example with `Object#remove_const`, must
+    /// invalidate the cache before the next resolution.
+    pub fn invalidate_rclass_cache(&self) {
+        self.resolved.set(None);
     }
 }
 
@@ -329,6 +480,76 @@ mod tests {
         assert!(result, "RustError inherits from StandardError");
     }
 
+    struct Hidden;
+
+    unsafe extern "C" fn hidden_answer(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let mut interp = unwrap_interpreter!(mrb);
+        let guard = Guard::new(&mut interp);
+        let value = guard.convert(29_i64);
+        value.inner()
+    }
+
+    #[test]
+    fn private_method() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = class::Spec::new("Hidden", None, None).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .add_private_method("answer", hidden_answer, sys::mrb_args_none())
+            .unwrap()
+            .define()
+            .unwrap();
+        interp.def_class::<Hidden>(spec).unwrap();
+
+        let result = interp
+            .eval(b"class Hidden; def implicit; answer; end; end; Hidden.new.implicit")
+            .unwrap();
+        let result = result.try_into::<i64>(&interp).unwrap();
+        assert_eq!(
+            result, 29,
+            "private methods are callable with an implicit receiver"
+        );
+        let err = interp.eval(b"Hidden.new.answer").unwrap_err();
+        assert_eq!("NoMethodError", err.name().as_ref());
+        assert!(
+            err.message().starts_with(b"private method 'answer' called for "),
+            "expected private method NoMethodError, got: {:?}",
+            String::from_utf8_lossy(err.message().as_ref())
+        );
+    }
+
+    struct Shielded;
+
+    #[test]
+    fn protected_method() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = class::Spec::new("Shielded", None, None).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .add_protected_method("answer", hidden_answer, sys::mrb_args_none())
+            .unwrap()
+            .define()
+            .unwrap();
+        interp.def_class::<Shielded>(spec).unwrap();
+
+        let result = interp
+            .eval(b"class Shielded; def peer(other); other.answer; end; end; Shielded.new.peer(Shielded.new)")
+            .unwrap();
+        let result = result.try_into::<i64>(&interp).unwrap();
+        assert_eq!(
+            result, 29,
+            "protected methods are callable when the caller is an instance of the defining class"
+        );
+        let err = interp.eval(b"Shielded.new.answer").unwrap_err();
+        assert_eq!("NoMethodError", err.name().as_ref());
+        assert!(
+            err.message().starts_with(b"protected method 'answer' called for "),
+            "expected protected method NoMethodError, got: {:?}",
+            String::from_utf8_lossy(err.message().as_ref())
+        );
+    }
+
     #[test]
     fn rclass_for_undef_root_class() {
         let mut interp = crate::interpreter().unwrap();
@@ -365,4 +586,205 @@ mod tests {
         let rclass = unsafe { interp.with_ffi_boundary(|mrb| spec.rclass().resolve(mrb)) }.unwrap();
         assert!(rclass.is_some());
     }
+
+    struct Plugin;
+
+    #[test]
+    fn with_super_class_value_inherits_ruby_defined_class() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp
+            .eval(b"class PluginBase; def kind; :plugin; end; end")
+            .unwrap();
+        let super_class = interp.eval(b"PluginBase").unwrap();
+        let spec = class::Spec::new("NativePlugin", None, None).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .with_super_class_value(super_class)
+            .unwrap()
+            .define()
+            .unwrap();
+        interp.def_class::<Plugin>(spec).unwrap();
+        let result = interp.eval(b"NativePlugin.new.kind == :plugin").unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"NativePlugin.superclass == PluginBase")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn with_super_class_value_requires_a_class() {
+        let mut interp = crate::interpreter().unwrap();
+        let not_a_class = interp.eval(b"'definitely not a class'").unwrap();
+        let spec = class::Spec::new("NativePlugin", None, None).unwrap();
+        let err = class::Builder::for_spec(&mut interp, &spec)
+            .with_super_class_value(not_a_class)
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!("TypeError", err.name().as_ref());
+        assert_eq!(
+            &b"superclass must be a Class (String given)"[..],
+            err.message().as_ref()
+        );
+    }
+
+    #[test]
+    fn class_value_resolves_nested_class() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp.eval(b"module Scope; class Leaf; end; end").unwrap();
+        let scope = module::Spec::new(&mut interp, "Scope", None).unwrap();
+        let spec =
+            class::Spec::new("Leaf", Some(EnclosingRubyScope::module(&scope)), None).unwrap();
+        let class = spec.class_value(&mut interp).unwrap().unwrap();
+        let name = class.funcall(&mut interp, "name", &[], None).unwrap();
+        let name = name.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("Scope::Leaf", name);
+
+        let spec = class::Spec::new("NotDefinedAnywhere", None, None).unwrap();
+        assert!(spec.class_value(&mut interp).unwrap().is_none());
+    }
+
+    #[test]
+    fn rclass_resolution_is_cached_per_spec() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp
+            .eval(b"module Outer; module Inner; class Deep; end; end; end")
+            .unwrap();
+        let outer = module::Spec::new(&mut interp, "Outer", None).unwrap();
+        let inner = module::Spec::new(
+            &mut interp,
+            "Inner",
+            Some(EnclosingRubyScope::module(&outer)),
+        )
+        .unwrap();
+        let spec =
+            class::Spec::new("Deep", Some(EnclosingRubyScope::module(&inner)), None).unwrap();
+        let first = unsafe { interp.with_ffi_boundary(|mrb| spec.rclass().resolve(mrb)) }
+            .unwrap()
+            .unwrap();
+        // The second resolution through the same spec is served from the
+        // cache without walking the enclosing scope.
+        let second = unsafe { interp.with_ffi_boundary(|mrb| spec.rclass().resolve(mrb)) }
+            .unwrap()
+            .unwrap();
+        assert_eq!(first, second);
+        spec.invalidate_rclass_cache();
+        let third = unsafe { interp.with_ffi_boundary(|mrb| spec.rclass().resolve(mrb)) }
+            .unwrap()
+            .unwrap();
+        assert_eq!(first, third);
+    }
+
+    struct Reopened;
+
+    unsafe extern "C" fn reopened_original(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let mut interp = unwrap_interpreter!(mrb);
+        let guard = Guard::new(&mut interp);
+        let value = guard.convert(1_i64);
+        value.inner()
+    }
+
+    unsafe extern "C" fn reopened_added(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        let mut interp = unwrap_interpreter!(mrb);
+        let guard = Guard::new(&mut interp);
+        let value = guard.convert(2_i64);
+        value.inner()
+    }
+
+    #[test]
+    fn reopen_class_appends_methods() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = class::Spec::new("Reopened", None, None).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .add_method("original", reopened_original, sys::mrb_args_none())
+            .unwrap()
+            .define()
+            .unwrap();
+        interp.def_class::<Reopened>(spec).unwrap();
+
+        let builder = interp.reopen_class::<Reopened>().unwrap().unwrap();
+        builder
+            .add_method("added", reopened_added, sys::mrb_args_none())
+            .unwrap()
+            .define()
+            .unwrap();
+
+        // Reopening does not clobber previously defined methods.
+        let result = interp.eval(b"obj = Reopened.new; [obj.original, obj.added]").unwrap();
+        let result = result.try_into_mut::<Vec<i64>>(&mut interp).unwrap();
+        assert_eq!(vec![1, 2], result);
+
+        // `define` is idempotent for identical specs.
+        let builder = interp.reopen_class::<Reopened>().unwrap().unwrap();
+        builder
+            .add_method("added", reopened_added, sys::mrb_args_none())
+            .unwrap()
+            .define()
+            .unwrap();
+        let result = interp.eval(b"obj = Reopened.new; [obj.original, obj.added]").unwrap();
+        let result = result.try_into_mut::<Vec<i64>>(&mut interp).unwrap();
+        assert_eq!(vec![1, 2], result);
+    }
+
+    #[test]
+    fn class_of_name_resolves_vm_classes() {
+        let mut interp = crate::interpreter().unwrap();
+        let class = interp.class_of_name("String").unwrap().unwrap();
+        let name = class.funcall(&mut interp, "name", &[], None).unwrap();
+        let name = name.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("String", name);
+        assert!(interp.class_of_name("NotDefinedAnywhere").unwrap().is_none());
+    }
+
+    struct Yielder;
+
+    #[test]
+    fn new_instance_with_block_yields_to_initialize() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = class::Spec::new("Yielder", None, None).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .define()
+            .unwrap();
+        interp.def_class::<Yielder>(spec).unwrap();
+        let _ = interp
+            .eval(b"class Yielder; attr_accessor :tag; def initialize; yield self; end; end")
+            .unwrap();
+
+        let block = interp.eval(b"proc { |obj| obj.tag = 'yielded' }").unwrap();
+        let instance = interp
+            .new_instance_with_block::<Yielder>(&[], block)
+            .unwrap()
+            .unwrap();
+        let tag = instance.funcall(&mut interp, "tag", &[], None).unwrap();
+        let tag = tag.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("yielded", tag);
+    }
+
+    struct Ephemeral;
+
+    #[test]
+    fn undef_class_removes_rust_binding() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = class::Spec::new("Ephemeral", None, None).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .define()
+            .unwrap();
+        interp.def_class::<Ephemeral>(spec).unwrap();
+        assert!(interp.is_class_defined::<Ephemeral>());
+
+        let spec = interp.undef_class::<Ephemeral>().unwrap().unwrap();
+        assert_eq!("Ephemeral", spec.name().as_ref());
+        assert!(!interp.is_class_defined::<Ephemeral>());
+        assert!(interp.undef_class::<Ephemeral>().unwrap().is_none());
+
+        // Only the Rust-side binding is removed; the constant remains defined
+        // in the live VM.
+        let result = interp.eval(b"defined?(Ephemeral)").unwrap();
+        assert!(!result.is_nil());
+    }
 }
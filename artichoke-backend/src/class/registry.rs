@@ -212,6 +212,22 @@ where
         Some(value.as_ref())
     }
 
+    /// Removes and returns the [class spec](Spec) corresponding to the type
+    /// key.
+    ///
+    /// If the type `K` has not been registered, [`None`] is returned.
+    ///
+    /// The returned `Box` owns the spec's `mrb_data_type`. Callers must
+    /// ensure no live objects in the mruby VM reference the data type before
+    /// dropping it.
+    pub fn remove<K>(&mut self) -> Option<Box<Spec>>
+    where
+        K: Any,
+    {
+        let key = TypeId::of::<K>();
+        self.0.remove(&key)
+    }
+
     /// Reserves `capacity` for at least additional more elements to be inserted
     /// in the `Registry`. The collection may reserve more space to avoid
     /// frequent reallocations.
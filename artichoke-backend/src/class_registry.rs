@@ -1,7 +1,11 @@
 use std::any::Any;
 use std::convert::TryFrom;
+use std::ffi::CString;
+use std::ptr::NonNull;
 
 use crate::class;
+use crate::core::Value as _;
+use crate::def::ConstantNameError;
 use crate::exception::Exception;
 use crate::ffi::InterpreterExtractError;
 use crate::sys;
@@ -14,6 +18,10 @@ pub trait ClassRegistry {
     where
         T: Any;
 
+    fn undef_class<T>(&mut self) -> Result<Option<class::Spec>, Exception>
+    where
+        T: Any;
+
     fn class_spec<T>(&self) -> Result<Option<&class::Spec>, Exception>
     where
         T: Any;
@@ -33,9 +41,23 @@ pub trait ClassRegistry {
     where
         T: Any;
 
+    fn class_of_name(&mut self, name: &str) -> Result<Option<Value>, Exception>;
+
+    fn reopen_class<T>(&mut self) -> Result<Option<class::Builder<'_>>, Exception>
+    where
+        T: Any;
+
     fn new_instance<T>(&mut self, args: &[Value]) -> Result<Option<Value>, Exception>
     where
         T: Any;
+
+    fn new_instance_with_block<T>(
+        &mut self,
+        args: &[Value],
+        block: Value,
+    ) -> Result<Option<Value>, Exception>
+    where
+        T: Any;
 }
 
 impl ClassRegistry for Artichoke {
@@ -53,6 +75,25 @@ impl ClassRegistry for Artichoke {
         Ok(())
     }
 
+    /// Remove the class definition bound to a Rust type `T` and return it.
+    ///
+    /// This function returns `None` if type `T` has not had a class spec
+    /// registered for it using [`ClassRegistry::def_class`].
+    ///
+    /// Removing a spec only unbinds it on the Rust side; the class constant
+    /// remains defined in the live mruby VM. Removing the constant requires
+    /// evaling `remove_const` in the VM. Because the returned spec owns the
+    /// `mrb_data_type` for `T`, callers must ensure no live objects of the
+    /// data type remain before dropping it.
+    fn undef_class<T>(&mut self) -> Result<Option<class::Spec>, Exception>
+    where
+        T: Any,
+    {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        let spec = state.classes.remove::<T>();
+        Ok(spec.map(|spec| *spec))
+    }
+
     /// Retrieve a class definition from the state bound to Rust type `T`.
     ///
     /// This function returns `None` if type `T` has not had a class spec
@@ -90,6 +131,56 @@ impl ClassRegistry for Artichoke {
         Ok(value_class)
     }
 
+    /// Resolve a class `Value` by constant name in the root scope of the VM.
+    ///
+    /// Unlike [`ClassRegistry::class_of`], this lookup does not require a
+    /// Rust-side binding; any class reachable from the top-level constant
+    /// namespace can be resolved. This function returns `None` if no class
+    /// with the given name is defined.
+    fn class_of_name(&mut self, name: &str) -> Result<Option<Value>, Exception> {
+        let class_name =
+            CString::new(name).map_err(|_| ConstantNameError::from(String::from(name)))?;
+        let value_class = unsafe {
+            self.with_ffi_boundary(|mrb| {
+                if sys::mrb_class_defined(mrb, class_name.as_ptr()) == 0 {
+                    None
+                } else {
+                    let rclass = sys::mrb_class_get(mrb, class_name.as_ptr());
+                    let mut rclass = NonNull::new(rclass)?;
+                    let value_class = sys::mrb_sys_class_value(rclass.as_mut());
+                    Some(Value::from(value_class))
+                }
+            })?
+        };
+        Ok(value_class)
+    }
+
+    /// Reopen the class definition bound to a Rust type `T` with a
+    /// [`class::Builder`].
+    ///
+    /// The builder is bound to the already-registered spec, so additional
+    /// methods can be appended and `define()`d without clobbering methods
+    /// defined on the live class. This function returns `None` if type `T`
+    /// has not had a class spec registered for it using
+    /// [`ClassRegistry::def_class`].
+    fn reopen_class<T>(&mut self) -> Result<Option<class::Builder<'_>>, Exception>
+    where
+        T: Any,
+    {
+        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+        let spec = if let Some(spec) = state.classes.get::<T>() {
+            NonNull::from(spec)
+        } else {
+            return Ok(None);
+        };
+        // Safety: class specs are stored behind a `Box` in the registry, so
+        // the spec's address is stable. Mutating the registry requires
+        // `&mut Artichoke`, which the returned builder holds exclusively, so
+        // the spec cannot be removed or dropped while the builder is live.
+        let spec = unsafe { &*spec.as_ptr() };
+        Ok(Some(class::Builder::for_spec(self, spec)))
+    }
+
     fn new_instance<T>(&mut self, args: &[Value]) -> Result<Option<Value>, Exception>
     where
         T: Any,
@@ -120,4 +211,31 @@ impl ClassRegistry for Artichoke {
 
         Ok(instance)
     }
+
+    /// Construct an instance of the class bound to Rust type `T` with a block
+    /// attached to `initialize`.
+    ///
+    /// Construction dispatches through `#new` on the resolved class, which
+    /// mirrors a Ruby-land `Class.new(*args) { ... }` call and yields to
+    /// `initialize`s that expect a block. The argument count limit is the
+    /// same one `funcall` enforces.
+    ///
+    /// This function returns `None` if type `T` has not had a class spec
+    /// registered for it using [`ClassRegistry::def_class`].
+    fn new_instance_with_block<T>(
+        &mut self,
+        args: &[Value],
+        block: Value,
+    ) -> Result<Option<Value>, Exception>
+    where
+        T: Any,
+    {
+        let class = if let Some(class) = self.class_of::<T>()? {
+            class
+        } else {
+            return Ok(None);
+        };
+        let instance = class.funcall(self, "new", args, Some(block))?;
+        Ok(Some(instance))
+    }
 }
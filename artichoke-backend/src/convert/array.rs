@@ -1,7 +1,7 @@
 use std::iter::FromIterator;
 
 use crate::convert::{BoxUnboxVmValue, UnboxRubyError};
-use crate::core::{Convert, ConvertMut, TryConvert, TryConvertMut};
+use crate::core::{Convert, ConvertMut, TryConvert, TryConvertMut, Value as _};
 use crate::exception::Exception;
 use crate::extn::core::array::Array;
 use crate::types::{Int, Ruby, Rust};
@@ -26,6 +26,19 @@ impl TryConvertMut<Vec<Value>, Value> for Artichoke {
     }
 }
 
+impl Artichoke {
+    /// Convert a `Vec` of `Value`s into a frozen Ruby `Array`.
+    ///
+    /// This is a convenience for exposing read-only collections like `ARGV`
+    /// to scripts: the array is allocated and frozen in one step, so mutating
+    /// method calls on it raise `FrozenError`.
+    pub fn frozen_array(&mut self, values: Vec<Value>) -> Result<Value, Exception> {
+        let mut ary = self.try_convert_mut(values)?;
+        ary.freeze(self)?;
+        Ok(ary)
+    }
+}
+
 impl TryConvertMut<&[Option<Value>], Value> for Artichoke {
     type Error = Exception;
 
@@ -391,6 +404,24 @@ mod tests {
 
     use crate::test::prelude::*;
 
+    #[test]
+    fn frozen_array_is_frozen_with_contents() {
+        let mut interp = crate::interpreter().unwrap();
+        let values = vec![
+            interp.convert(1),
+            interp.convert_mut("two"),
+            interp.convert(3.0),
+        ];
+        let ary = interp.frozen_array(values).unwrap();
+        assert!(ary.is_frozen(&mut interp));
+        let inspect = ary.funcall(&mut interp, "inspect", &[], None).unwrap();
+        let inspect = inspect.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!(r#"[1, "two", 3.0]"#, inspect);
+        let four = interp.convert(4);
+        let err = ary.funcall(&mut interp, "push", &[four], None).unwrap_err();
+        assert_eq!("FrozenError", err.name().as_ref());
+    }
+
     #[test]
     fn fail_convert() {
         let mut interp = crate::interpreter().unwrap();
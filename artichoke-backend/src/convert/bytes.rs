@@ -4,7 +4,7 @@ use std::ffi::{OsStr, OsString};
 use std::slice;
 
 use crate::convert::UnboxRubyError;
-use crate::core::{ConvertMut, TryConvertMut};
+use crate::core::{ConvertMut, TryConvert, TryConvertMut};
 use crate::exception::Exception;
 use crate::ffi;
 use crate::sys;
@@ -109,9 +109,26 @@ impl<'a> TryConvertMut<Value, &'a [u8]> for Artichoke {
     }
 }
 
+impl<'a> TryConvertMut<Value, Cow<'a, [u8]>> for Artichoke {
+    type Error = Exception;
+
+    fn try_convert_mut(&mut self, value: Value) -> Result<Cow<'a, [u8]>, Self::Error> {
+        let bytes = TryConvertMut::<_, &'a [u8]>::try_convert_mut(self, value)?;
+        let is_frozen = value.funcall(self, "frozen?", &[], None)?;
+        if self.try_convert(is_frozen)? {
+            // Frozen strings cannot be mutated, so the borrow into the mruby
+            // heap stays valid as long as `value` is reachable.
+            Ok(Cow::Borrowed(bytes))
+        } else {
+            Ok(Cow::Owned(bytes.to_vec()))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use quickcheck_macros::quickcheck;
+    use std::borrow::Cow;
 
     use crate::test::prelude::*;
 
@@ -124,6 +141,24 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn cow_borrows_frozen_strings() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"'artichoke'.freeze").unwrap();
+        let bytes = value.try_into_mut::<Cow<'_, [u8]>>(&mut interp).unwrap();
+        assert!(matches!(bytes, Cow::Borrowed(_)));
+        assert_eq!(bytes.as_ref(), b"artichoke");
+    }
+
+    #[test]
+    fn cow_owns_unfrozen_strings() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"'artichoke'.dup").unwrap();
+        let bytes = value.try_into_mut::<Cow<'_, [u8]>>(&mut interp).unwrap();
+        assert!(matches!(bytes, Cow::Owned(_)));
+        assert_eq!(bytes.as_ref(), b"artichoke");
+    }
+
     #[quickcheck]
     fn convert_to_vec(bytes: Vec<u8>) -> bool {
         let mut interp = crate::interpreter().unwrap();
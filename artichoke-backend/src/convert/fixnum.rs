@@ -135,6 +135,139 @@ impl TryConvert<Value, Int> for Artichoke {
     }
 }
 
+impl TryConvert<Value, i8> for Artichoke {
+    type Error = Exception;
+
+    fn try_convert(&self, value: Value) -> Result<i8, Self::Error> {
+        if let Ruby::Fixnum = value.ruby_type() {
+            let inner = value.inner();
+            let num = unsafe { sys::mrb_sys_fixnum_to_cint(inner) };
+            let num =
+                i8::try_from(num).map_err(|_| UnboxRubyError::new(&value, Rust::SignedInt))?;
+            Ok(num)
+        } else {
+            Err(Exception::from(UnboxRubyError::new(
+                &value,
+                Rust::SignedInt,
+            )))
+        }
+    }
+}
+
+impl TryConvert<Value, i16> for Artichoke {
+    type Error = Exception;
+
+    fn try_convert(&self, value: Value) -> Result<i16, Self::Error> {
+        if let Ruby::Fixnum = value.ruby_type() {
+            let inner = value.inner();
+            let num = unsafe { sys::mrb_sys_fixnum_to_cint(inner) };
+            let num =
+                i16::try_from(num).map_err(|_| UnboxRubyError::new(&value, Rust::SignedInt))?;
+            Ok(num)
+        } else {
+            Err(Exception::from(UnboxRubyError::new(
+                &value,
+                Rust::SignedInt,
+            )))
+        }
+    }
+}
+
+impl TryConvert<Value, i32> for Artichoke {
+    type Error = Exception;
+
+    fn try_convert(&self, value: Value) -> Result<i32, Self::Error> {
+        if let Ruby::Fixnum = value.ruby_type() {
+            let inner = value.inner();
+            let num = unsafe { sys::mrb_sys_fixnum_to_cint(inner) };
+            let num =
+                i32::try_from(num).map_err(|_| UnboxRubyError::new(&value, Rust::SignedInt))?;
+            Ok(num)
+        } else {
+            Err(Exception::from(UnboxRubyError::new(
+                &value,
+                Rust::SignedInt,
+            )))
+        }
+    }
+}
+
+impl TryConvert<Value, isize> for Artichoke {
+    type Error = Exception;
+
+    fn try_convert(&self, value: Value) -> Result<isize, Self::Error> {
+        if let Ruby::Fixnum = value.ruby_type() {
+            let inner = value.inner();
+            let num = unsafe { sys::mrb_sys_fixnum_to_cint(inner) };
+            let num =
+                isize::try_from(num).map_err(|_| UnboxRubyError::new(&value, Rust::SignedInt))?;
+            Ok(num)
+        } else {
+            Err(Exception::from(UnboxRubyError::new(
+                &value,
+                Rust::SignedInt,
+            )))
+        }
+    }
+}
+
+impl TryConvert<Value, u8> for Artichoke {
+    type Error = Exception;
+
+    fn try_convert(&self, value: Value) -> Result<u8, Self::Error> {
+        if let Ruby::Fixnum = value.ruby_type() {
+            let inner = value.inner();
+            let num = unsafe { sys::mrb_sys_fixnum_to_cint(inner) };
+            let num =
+                u8::try_from(num).map_err(|_| UnboxRubyError::new(&value, Rust::UnsignedInt))?;
+            Ok(num)
+        } else {
+            Err(Exception::from(UnboxRubyError::new(
+                &value,
+                Rust::SignedInt,
+            )))
+        }
+    }
+}
+
+impl TryConvert<Value, u16> for Artichoke {
+    type Error = Exception;
+
+    fn try_convert(&self, value: Value) -> Result<u16, Self::Error> {
+        if let Ruby::Fixnum = value.ruby_type() {
+            let inner = value.inner();
+            let num = unsafe { sys::mrb_sys_fixnum_to_cint(inner) };
+            let num =
+                u16::try_from(num).map_err(|_| UnboxRubyError::new(&value, Rust::UnsignedInt))?;
+            Ok(num)
+        } else {
+            Err(Exception::from(UnboxRubyError::new(
+                &value,
+                Rust::SignedInt,
+            )))
+        }
+    }
+}
+
+impl TryConvert<Value, u64> for Artichoke {
+    type Error = Exception;
+
+    fn try_convert(&self, value: Value) -> Result<u64, Self::Error> {
+        if let Ruby::Fixnum = value.ruby_type() {
+            let inner = value.inner();
+            let num = unsafe { sys::mrb_sys_fixnum_to_cint(inner) };
+            let num =
+                u64::try_from(num).map_err(|_| UnboxRubyError::new(&value, Rust::UnsignedInt))?;
+            Ok(num)
+        } else {
+            Err(Exception::from(UnboxRubyError::new(
+                &value,
+                Rust::SignedInt,
+            )))
+        }
+    }
+}
+
 impl TryConvert<Value, u32> for Artichoke {
     type Error = Exception;
 
@@ -176,6 +309,7 @@ impl TryConvert<Value, usize> for Artichoke {
 #[cfg(test)]
 mod tests {
     use quickcheck_macros::quickcheck;
+    use std::convert::TryFrom;
 
     use crate::test::prelude::*;
 
@@ -220,6 +354,72 @@ mod tests {
         value.is_err()
     }
 
+    #[quickcheck]
+    fn roundtrip_i8(i: i8) -> bool {
+        let interp = crate::interpreter().unwrap();
+        let value = interp.convert(i);
+        let value = value.try_into::<i8>(&interp).unwrap();
+        value == i
+    }
+
+    #[quickcheck]
+    fn roundtrip_i16(i: i16) -> bool {
+        let interp = crate::interpreter().unwrap();
+        let value = interp.convert(i);
+        let value = value.try_into::<i16>(&interp).unwrap();
+        value == i
+    }
+
+    #[quickcheck]
+    fn roundtrip_i32(i: i32) -> bool {
+        let interp = crate::interpreter().unwrap();
+        let value = interp.convert(i);
+        let value = value.try_into::<i32>(&interp).unwrap();
+        value == i
+    }
+
+    #[quickcheck]
+    fn roundtrip_u8(i: u8) -> bool {
+        let interp = crate::interpreter().unwrap();
+        let value = interp.convert(i);
+        let value = value.try_into::<u8>(&interp).unwrap();
+        value == i
+    }
+
+    #[quickcheck]
+    fn roundtrip_u16(i: u16) -> bool {
+        let interp = crate::interpreter().unwrap();
+        let value = interp.convert(i);
+        let value = value.try_into::<u16>(&interp).unwrap();
+        value == i
+    }
+
+    #[quickcheck]
+    fn roundtrip_u32(i: u32) -> bool {
+        let interp = crate::interpreter().unwrap();
+        let value = interp.convert(i);
+        let value = value.try_into::<u32>(&interp).unwrap();
+        value == i
+    }
+
+    #[test]
+    fn narrowing_out_of_range_is_an_error() {
+        let interp = crate::interpreter().unwrap();
+        let value = interp.convert(300_i64);
+        assert!(value.try_into::<i8>(&interp).is_err());
+        assert!(value.try_into::<u8>(&interp).is_err());
+        let value = interp.convert(-1_i64);
+        assert!(value.try_into::<u8>(&interp).is_err());
+        assert!(value.try_into::<u16>(&interp).is_err());
+        assert!(value.try_into::<u32>(&interp).is_err());
+        assert!(value.try_into::<u64>(&interp).is_err());
+        let value = interp.convert(Int::max_value());
+        assert_eq!(
+            u64::try_from(Int::max_value()).unwrap(),
+            value.try_into::<u64>(&interp).unwrap()
+        );
+    }
+
     #[test]
     fn fixnum_to_usize() {
         let interp = crate::interpreter().unwrap();
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::convert::TryFrom;
 
 use crate::convert::{BoxUnboxVmValue, UnboxRubyError};
@@ -101,13 +101,130 @@ impl TryConvertMut<Value, Vec<(Value, Value)>> for Artichoke {
     }
 }
 
+impl ConvertMut<BTreeMap<Vec<u8>, Vec<u8>>, Value> for Artichoke {
+    /// Convert an ordered byte map into a Ruby `Hash`.
+    ///
+    /// Entries are inserted in key-sorted order, so the Ruby hash's insertion
+    /// order -- which is its iteration order -- matches the `BTreeMap` order.
+    /// Ruby code can still reorder the hash; deterministic ordering is only
+    /// guaranteed on the Rust side after converting back to a `BTreeMap`.
+    fn convert_mut(&mut self, value: BTreeMap<Vec<u8>, Vec<u8>>) -> Value {
+        let capa = Int::try_from(value.len()).unwrap_or_default();
+        let hash = unsafe { self.with_ffi_boundary(|mrb| sys::mrb_hash_new_capa(mrb, capa)) };
+        let hash = hash.unwrap();
+        for (key, val) in value {
+            let key = self.convert_mut(key).inner();
+            let val = self.convert_mut(val).inner();
+            let _ = unsafe { self.with_ffi_boundary(|mrb| sys::mrb_hash_set(mrb, hash, key, val)) };
+        }
+        Value::from(hash)
+    }
+}
+
+impl TryConvertMut<Value, BTreeMap<Vec<u8>, Vec<u8>>> for Artichoke {
+    type Error = Exception;
+
+    /// Convert a Ruby `Hash` of `String` keys and values into an ordered byte
+    /// map.
+    ///
+    /// The resulting `BTreeMap` iterates in sorted key order regardless of
+    /// the Ruby hash's insertion order, which makes it suitable for
+    /// deterministic serialization. Keys and values must be `String`s; any
+    /// other type is a conversion error.
+    fn try_convert_mut(&mut self, value: Value) -> Result<BTreeMap<Vec<u8>, Vec<u8>>, Self::Error> {
+        let pairs = self.try_convert_mut::<Value, Vec<(Value, Value)>>(value)?;
+        let mut map = BTreeMap::new();
+        for (key, value) in pairs {
+            let key = key.try_into_mut::<Vec<u8>>(self)?;
+            let value = value.try_into_mut::<Vec<u8>>(self)?;
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+impl TryConvertMut<Value, HashMap<Vec<u8>, Vec<u8>>> for Artichoke {
+    type Error = Exception;
+
+    /// Convert a Ruby `Hash` of `String` keys and values into a byte map.
+    ///
+    /// This is the inverse of the `HashMap<Vec<u8>, Vec<u8>>` converter. Keys
+    /// and values must be `String`s; any other type is a conversion error.
+    /// Ruby `String` keys that are distinct objects but have equal byte
+    /// content collapse to one entry with the last value winning, matching
+    /// `Hash` semantics for equal keys.
+    fn try_convert_mut(&mut self, value: Value) -> Result<HashMap<Vec<u8>, Vec<u8>>, Self::Error> {
+        let pairs = self.try_convert_mut::<Value, Vec<(Value, Value)>>(value)?;
+        let mut map = HashMap::with_capacity(pairs.len());
+        for (key, value) in pairs {
+            let key = key.try_into_mut::<Vec<u8>>(self)?;
+            let value = value.try_into_mut::<Vec<u8>>(self)?;
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+impl TryConvertMut<Value, HashMap<String, Value>> for Artichoke {
+    type Error = Exception;
+
+    /// Convert a Ruby `Hash` with `String` keys into a map of UTF-8 validated
+    /// keys to boxed values.
+    ///
+    /// This is a common shape for config parsing: keys must be `String`s with
+    /// valid UTF-8 contents, values are left as [`Value`] handles. Non-string
+    /// keys and keys with invalid UTF-8 byte content are conversion errors.
+    fn try_convert_mut(&mut self, value: Value) -> Result<HashMap<String, Value>, Self::Error> {
+        let pairs = self.try_convert_mut::<Value, Vec<(Value, Value)>>(value)?;
+        let mut map = HashMap::with_capacity(pairs.len());
+        for (key, value) in pairs {
+            if let Ruby::String = key.ruby_type() {
+                let key = key.try_into_mut::<&str>(self)?;
+                map.insert(String::from(key), value);
+            } else {
+                return Err(Exception::from(UnboxRubyError::new(&key, Rust::String)));
+            }
+        }
+        Ok(map)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use quickcheck_macros::quickcheck;
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
     use crate::test::prelude::*;
 
+    #[test]
+    fn string_keyed_hash_converts_to_map() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp
+            .eval(b"{ 'host' => 'localhost', 'port' => 8080 }")
+            .unwrap();
+        let map = value
+            .try_into_mut::<HashMap<String, Value>>(&mut interp)
+            .unwrap();
+        assert_eq!(2, map.len());
+        let host = map.get("host").unwrap();
+        let host = host.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("localhost", host);
+        let port = map.get("port").unwrap();
+        assert_eq!(8080, port.try_into::<Int>(&interp).unwrap());
+    }
+
+    #[test]
+    fn non_string_and_invalid_utf8_keys_are_conversion_errors() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"{ sym: 1 }").unwrap();
+        let result = value.try_into_mut::<HashMap<String, Value>>(&mut interp);
+        assert!(result.is_err());
+
+        let value = interp.eval(b"{ \"\xff\" => 1 }").unwrap();
+        let result = value.try_into_mut::<HashMap<String, Value>>(&mut interp);
+        assert!(result.is_err());
+    }
+
     #[quickcheck]
     fn roundtrip_kv(hash: HashMap<Vec<u8>, Vec<u8>>) -> bool {
         let mut interp = crate::interpreter().unwrap();
@@ -131,6 +248,52 @@ mod tests {
                 _ => return false,
             }
         }
-        true
+        // The direct inverse converter recovers the original map in one call.
+        let recovered = value
+            .try_into_mut::<HashMap<Vec<u8>, Vec<u8>>>(&mut interp)
+            .unwrap();
+        recovered == hash
+    }
+
+    #[quickcheck]
+    fn roundtrip_btree_kv(hash: BTreeMap<Vec<u8>, Vec<u8>>) -> bool {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.convert_mut(hash.clone());
+        let len = value.funcall(&mut interp, "length", &[], None).unwrap();
+        let len = len.try_into::<usize>(&interp).unwrap();
+        if len != hash.len() {
+            return false;
+        }
+        let recovered = value
+            .try_into_mut::<BTreeMap<Vec<u8>, Vec<u8>>>(&mut interp)
+            .unwrap();
+        recovered == hash
+    }
+
+    #[test]
+    fn btree_recovery_is_key_sorted() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp
+            .eval(b"{ 'zebra' => 'z', 'apple' => 'a', 'mango' => 'm' }")
+            .unwrap();
+        let map = value
+            .try_into_mut::<BTreeMap<Vec<u8>, Vec<u8>>>(&mut interp)
+            .unwrap();
+        let keys = map.keys().cloned().collect::<Vec<_>>();
+        assert_eq!(
+            vec![b"apple".to_vec(), b"mango".to_vec(), b"zebra".to_vec()],
+            keys
+        );
+    }
+
+    #[test]
+    fn non_string_keys_and_values_are_conversion_errors() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"{ 'key' => :value }").unwrap();
+        let result = value.try_into_mut::<HashMap<Vec<u8>, Vec<u8>>>(&mut interp);
+        assert!(result.is_err());
+        let value = interp.eval(b"{ 1 => 'value' }").unwrap();
+        let result = value.try_into_mut::<HashMap<Vec<u8>, Vec<u8>>>(&mut interp);
+        assert!(result.is_err());
     }
 }
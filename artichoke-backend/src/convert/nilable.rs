@@ -4,7 +4,7 @@
 
 use crate::core::{Convert, ConvertMut, TryConvert, TryConvertMut, Value as _};
 use crate::exception::Exception;
-use crate::types::Int;
+use crate::types::{Fp, Int};
 use crate::value::Value;
 use crate::Artichoke;
 
@@ -24,6 +24,16 @@ impl Convert<Option<Int>, Value> for Artichoke {
     }
 }
 
+impl ConvertMut<Option<Fp>, Value> for Artichoke {
+    fn convert_mut(&mut self, value: Option<Fp>) -> Value {
+        if let Some(value) = value {
+            self.convert_mut(value)
+        } else {
+            Value::nil()
+        }
+    }
+}
+
 impl ConvertMut<Option<Vec<u8>>, Value> for Artichoke {
     fn convert_mut(&mut self, value: Option<Vec<u8>>) -> Value {
         self.convert_mut(value.as_deref())
@@ -137,3 +147,55 @@ impl TryConvert<Value, Option<Int>> for Artichoke {
         }
     }
 }
+
+impl TryConvert<Value, Option<Fp>> for Artichoke {
+    type Error = Exception;
+
+    fn try_convert(&self, value: Value) -> Result<Option<Fp>, Self::Error> {
+        if value.is_nil() {
+            Ok(None)
+        } else {
+            self.try_convert(value).map(Some)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn none_converts_to_nil() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.convert(None::<Int>);
+        assert!(value.is_nil());
+        let value = interp.convert_mut(None::<Fp>);
+        assert!(value.is_nil());
+        let value = interp.convert_mut(None::<Vec<u8>>);
+        assert!(value.is_nil());
+    }
+
+    #[test]
+    fn some_converts_to_inner_value() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.convert(Some(28));
+        assert_eq!(28, value.try_into::<Int>(&interp).unwrap());
+        let value = interp.convert_mut(Some(1.5));
+        assert!((value.try_into::<Fp>(&interp).unwrap() - 1.5).abs() < Fp::EPSILON);
+        let value = interp.convert_mut(Some(b"nilable".to_vec()));
+        let value = value.try_into_mut::<Vec<u8>>(&mut interp).unwrap();
+        assert_eq!(b"nilable".to_vec(), value);
+    }
+
+    #[test]
+    fn nilable_extraction_round_trips() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"nil").unwrap();
+        assert_eq!(None, value.try_into::<Option<Int>>(&interp).unwrap());
+        assert_eq!(None, value.try_into::<Option<Fp>>(&interp).unwrap());
+        let value = interp.eval(b"2.5").unwrap();
+        assert_eq!(Some(2.5), value.try_into::<Option<Fp>>(&interp).unwrap());
+        let value = interp.eval(b"'not a float'").unwrap();
+        assert!(value.try_into::<Option<Fp>>(&interp).is_err());
+    }
+}
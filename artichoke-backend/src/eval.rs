@@ -2,13 +2,15 @@ use bstr::ByteSlice;
 use std::ffi::OsStr;
 use std::path::Path;
 
-use crate::core::{Eval, LoadSources, Parser, Value as _};
+use crate::core::{Eval, Globals, LoadSources, Parser, Value as _};
 use crate::exception::Exception;
 use crate::exception_handler;
 use crate::extn::core::exception::{ArgumentError, Fatal};
 use crate::ffi::{self, InterpreterExtractError};
+use crate::state::limits::{budget_allocf, budget_code_fetch_hook, ExecutionLimits};
+use crate::state::output::Captured;
 use crate::state::parser::Context;
-use crate::sys::protect;
+use crate::sys::{self, protect};
 use crate::value::Value;
 use crate::Artichoke;
 
@@ -38,6 +40,11 @@ impl Eval for Artichoke {
                     Err(Fatal::from("Unreachable Ruby value").into())
                 } else {
                     trace!("Sucessful eval");
+                    // A successful eval means any previously extracted error
+                    // has been handled, so clear the in-flight error tracking
+                    // to avoid recording it as the `cause` of unrelated
+                    // future errors.
+                    self.unset_global_variable(exception_handler::ERRINFO)?;
                     Ok(value)
                 }
             }
@@ -66,10 +73,205 @@ impl Eval for Artichoke {
     }
 }
 
+impl Artichoke {
+    /// Compile `code` on the interpreter without executing it.
+    ///
+    /// This function validates Ruby syntax the way [`Eval::eval`] does --
+    /// including the active parser [`Context`] for `__FILE__` and line
+    /// numbers -- but sets the parser's `no_exec` flag so the compiled code
+    /// is discarded instead of run. This makes it suitable for linters and
+    /// other tools that must not observe side effects from the checked
+    /// source.
+    ///
+    /// # Errors
+    ///
+    /// If the source fails to parse, a `SyntaxError` describing the failure
+    /// position is returned.
+    pub fn check_syntax(&mut self, code: &[u8]) -> Result<(), Exception> {
+        trace!("Attempting syntax-only compile of Ruby source");
+        let result = unsafe {
+            let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+            let parser = state.parser.as_mut().ok_or(InterpreterExtractError)?;
+            let context = parser.context_mut() as *mut sys::mrbc_context;
+            (*context).set_no_exec(1);
+            let result = self.with_ffi_boundary(|mrb| protect::eval(mrb, context, code));
+            (*context).set_no_exec(0);
+            result?
+        };
+        match result {
+            // The compiled code is returned as a `Proc` and discarded
+            // unexecuted.
+            Ok(_) => Ok(()),
+            Err(exception) => {
+                let exception = Value::from(exception);
+                let debug = exception.inspect(self);
+                debug!("Syntax check raised exception: {:?}", debug.as_bstr());
+                Err(exception_handler::last_error(self, exception)?)
+            }
+        }
+    }
+
+    /// Eval `code` on the interpreter while capturing its stdout.
+    ///
+    /// Output written via `Kernel#puts`, `Kernel#print`, and `Kernel#p` during
+    /// the eval is redirected into a buffer instead of the interpreter's
+    /// configured output strategy. The previous output configuration is
+    /// restored before returning, even when the eval raises. This is suited to
+    /// doc-test style harnesses that assert on both the result and the printed
+    /// output of a snippet.
+    ///
+    /// Nested calls compose: each capture only observes output written while
+    /// it is the innermost one.
+    ///
+    /// # Errors
+    ///
+    /// If the eval raises, the exception is returned and the captured output
+    /// is discarded.
+    pub fn eval_capturing_output(&mut self, code: &[u8]) -> Result<(Value, Vec<u8>), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        let prior = state.capture.replace(Captured::new());
+        let result = self.eval(code);
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        let captured = std::mem::replace(&mut state.capture, prior);
+        let value = result?;
+        let output = captured.map(|capture| capture.stdout().to_vec());
+        Ok((value, output.unwrap_or_default()))
+    }
+
+    /// Eval `code` on the interpreter under per-call resource limits.
+    ///
+    /// [`ExecutionLimits`] bundles a VM step budget, a call-stack depth limit,
+    /// and a heap-allocation cap so embedders have a single knob for running
+    /// untrusted code. Exceeding a limit raises a distinct, catchable
+    /// exception: `StepLimitExceeded`, `SystemStackError`, or `NoMemoryError`.
+    /// Enforcement is uninstalled before returning, even when the eval raises.
+    ///
+    /// # Errors
+    ///
+    /// If the eval raises -- including by exceeding one of `limits` -- the
+    /// exception is returned.
+    pub fn eval_limited(
+        &mut self,
+        code: &[u8],
+        limits: ExecutionLimits,
+    ) -> Result<Value, Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        let prior_budget = state.limits.replace(limits.into());
+        let (prior_hook, prior_allocf) = unsafe {
+            self.with_ffi_boundary(|mrb| {
+                let hook =
+                    std::mem::replace(&mut (*mrb).code_fetch_hook, Some(budget_code_fetch_hook));
+                let allocf = std::mem::replace(&mut (*mrb).allocf, Some(budget_allocf));
+                (hook, allocf)
+            })?
+        };
+        let result = self.eval(code);
+        unsafe {
+            self.with_ffi_boundary(|mrb| {
+                (*mrb).code_fetch_hook = prior_hook;
+                (*mrb).allocf = prior_allocf;
+            })?;
+        }
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.limits = prior_budget;
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::path::Path;
+
     use crate::test::prelude::*;
 
+    #[test]
+    fn eval_capturing_output_returns_value_and_stdout() {
+        let mut interp = crate::interpreter().unwrap();
+        let (value, output) = interp.eval_capturing_output(b"puts 42; :ok").unwrap();
+        assert_eq!(&b"42\n"[..], output.as_slice());
+        assert_eq!(Ruby::Symbol, value.ruby_type());
+        assert_eq!(&b":ok"[..], value.inspect(&mut interp).as_slice());
+    }
+
+    #[test]
+    fn eval_capturing_output_restores_output_on_raise() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp
+            .eval_capturing_output(b"puts 'partial'; raise ArgumentError")
+            .unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+        // The capture is uninstalled even though the eval raised.
+        let (_, output) = interp.eval_capturing_output(b"print 'after'").unwrap();
+        assert_eq!(&b"after"[..], output.as_slice());
+    }
+
+    mod limited {
+        use crate::state::limits::ExecutionLimits;
+        use crate::test::prelude::*;
+
+        #[test]
+        fn unlimited_eval_behaves_like_eval() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp
+                .eval_limited(b"2 + 2", ExecutionLimits::new())
+                .unwrap();
+            assert_eq!(4, result.try_into::<Int>(&interp).unwrap());
+        }
+
+        #[test]
+        fn step_budget_raises_step_limit_exceeded() {
+            let mut interp = crate::interpreter().unwrap();
+            let limits = ExecutionLimits::new().with_step_limit(10_000);
+            let err = interp.eval_limited(b"loop { }", limits).unwrap_err();
+            assert_eq!("StepLimitExceeded", err.name().as_ref());
+            assert_eq!(&b"step limit exceeded"[..], err.message().as_ref());
+            // Enforcement is uninstalled after the limited eval.
+            let result = interp.eval(b"2 + 2").unwrap();
+            assert_eq!(4, result.try_into::<Int>(&interp).unwrap());
+        }
+
+        #[test]
+        fn step_limit_exceeded_is_rescuable() {
+            let mut interp = crate::interpreter().unwrap();
+            let limits = ExecutionLimits::new().with_step_limit(10_000);
+            // The budget is disarmed once it trips, so the rescue clause runs.
+            let result = interp
+                .eval_limited(
+                    b"begin; loop { }; rescue StepLimitExceeded; :rescued; end",
+                    limits,
+                )
+                .unwrap();
+            assert_eq!(&b":rescued"[..], result.inspect(&mut interp).as_slice());
+        }
+
+        #[test]
+        fn stack_depth_limit_raises_system_stack_error() {
+            let mut interp = crate::interpreter().unwrap();
+            // This recursion completes under the VM's own depth limit; only
+            // the configured limit can cut it short.
+            let code = b"def recurse(n); recurse(n + 1) unless n == 64; end; recurse(0)";
+            let result = interp.eval(code).unwrap();
+            assert!(result.is_nil());
+            let limits = ExecutionLimits::new().with_stack_depth_limit(16);
+            let err = interp.eval_limited(code, limits).unwrap_err();
+            assert_eq!("SystemStackError", err.name().as_ref());
+            assert_eq!(&b"stack level too deep"[..], err.message().as_ref());
+        }
+
+        #[test]
+        fn heap_limit_raises_no_memory_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let limits = ExecutionLimits::new().with_heap_limit(1024 * 1024);
+            let err = interp
+                .eval_limited(b"'x' * (8 * 1024 * 1024)", limits)
+                .unwrap_err();
+            assert_eq!("NoMemoryError", err.name().as_ref());
+            // The default allocator is reinstalled after the limited eval.
+            let result = interp.eval(b"('x' * (8 * 1024 * 1024)).length").unwrap();
+            assert_eq!(8 * 1024 * 1024, result.try_into::<Int>(&interp).unwrap());
+        }
+    }
+
     #[test]
     fn root_eval_context() {
         let mut interp = crate::interpreter().unwrap();
@@ -215,6 +417,50 @@ mod tests {
         assert_eq!(result, "(eval)");
     }
 
+    #[test]
+    // TODO(GH-528): fix failing tests on Windows.
+    #[cfg_attr(target_os = "windows", should_panic)]
+    fn eval_file_sets_file_magic_constant() {
+        let mut interp = crate::interpreter().unwrap();
+        interp
+            .def_rb_source_file("/script.rb", &b"[__FILE__, 2 + 2].inspect"[..])
+            .unwrap();
+        let result = interp.eval_file(Path::new("/script.rb")).unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!(r#"["/script.rb", 4]"#, result);
+        // The eval context is restored after the file is evaluated.
+        let result = interp.eval(b"__FILE__").unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("(eval)", result);
+    }
+
+    #[test]
+    fn eval_file_on_missing_file_is_an_io_error() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.eval_file(Path::new("/no/such/script.rb")).unwrap_err();
+        assert_eq!("IOError", err.name().as_ref());
+    }
+
+    #[test]
+    fn check_syntax_does_not_execute_valid_code() {
+        let mut interp = crate::interpreter().unwrap();
+        interp.check_syntax(b"x = 1 + 1").unwrap();
+        interp.check_syntax(b"$side_effect = 'executed'").unwrap();
+        // The source compiles but never runs.
+        let result = interp.eval(b"$side_effect").unwrap();
+        assert!(result.is_nil());
+    }
+
+    #[test]
+    fn check_syntax_reports_syntax_errors() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.check_syntax(b"def bad; 'as'.scan(; end").unwrap_err();
+        assert_eq!("SyntaxError", err.name().as_ref());
+        // The interpreter still executes code after a failed syntax check.
+        let result = interp.eval(b"2 + 2").unwrap();
+        assert_eq!(4, result.try_into::<Int>(&interp).unwrap());
+    }
+
     #[test]
     fn return_syntax_error() {
         let mut interp = crate::interpreter().unwrap();
@@ -3,16 +3,125 @@ use std::borrow::Cow;
 use std::error;
 use std::fmt;
 use std::hint;
+use std::panic::{self, AssertUnwindSafe};
 
-use crate::core::{TryConvertMut, Value as _};
+use crate::class_registry::ClassRegistry;
+use crate::core::{ConvertMut, TryConvertMut, Value as _};
+use crate::exception_handler;
+use crate::extn::core::exception::{Fatal, NameError};
 use crate::string;
 use crate::sys;
 use crate::value::Value;
 use crate::{Artichoke, Guard};
 
+/// Order in which [`Exception::full_message`] renders backtrace frames.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BacktraceOrder {
+    /// Render the error message first, followed by the backtrace frames from
+    /// innermost to outermost.
+    Top,
+    /// Render the backtrace frames from outermost to innermost, followed by
+    /// the error message.
+    ///
+    /// This order keeps the error message adjacent to the prompt when dumping
+    /// errors to a terminal.
+    Bottom,
+}
+
+impl Default for BacktraceOrder {
+    fn default() -> Self {
+        Self::Top
+    }
+}
+
 #[derive(Debug)]
 pub struct Exception(Box<dyn RubyException>);
 
+impl Exception {
+    /// Read the backtrace captured on the underlying Ruby exception.
+    ///
+    /// The backtrace is returned as a `Vec` of frames, each a byte string of
+    /// the form `file:line`. Returns `None` when the exception has no
+    /// backtrace, for example because it was freshly constructed and never
+    /// raised, or because the underlying value cannot be materialized in the
+    /// VM.
+    #[must_use]
+    pub fn backtrace(&self, interp: &mut Artichoke) -> Option<Vec<Vec<u8>>> {
+        let exception = Value::from(self.as_mrb_value(interp)?);
+        let backtrace = exception.funcall(interp, "backtrace", &[], None).ok()?;
+        backtrace.try_into_mut::<Vec<Vec<u8>>>(interp).ok()
+    }
+
+    /// Read the error that was in flight when this exception was raised.
+    ///
+    /// Ruby records the error being handled as the new error's `cause` when an
+    /// exception is raised while another is in flight. Returns `None` when the
+    /// exception has no recorded cause or when the underlying value cannot be
+    /// materialized in the VM.
+    #[must_use]
+    pub fn cause(&self, interp: &mut Artichoke) -> Option<Value> {
+        let exception = Value::from(self.as_mrb_value(interp)?);
+        let cause = exception.funcall(interp, "cause", &[], None).ok()?;
+        if cause.is_nil() {
+            None
+        } else {
+            Some(cause)
+        }
+    }
+
+    /// Render this exception the way MRI's `Exception#full_message` does.
+    ///
+    /// The error message and class name are assembled into a
+    /// `<message> (<class>)` line and combined with the backtrace frames in
+    /// the given [`BacktraceOrder`]. When `highlight` is true, the class name
+    /// is wrapped in ANSI bold escape sequences for terminal output.
+    /// Exceptions without a backtrace render only the message line.
+    #[must_use]
+    pub fn full_message(
+        &self,
+        interp: &mut Artichoke,
+        highlight: bool,
+        order: BacktraceOrder,
+    ) -> Vec<u8> {
+        const HIGHLIGHT_BEGIN: &[u8] = b"\x1b[1m";
+        const HIGHLIGHT_END: &[u8] = b"\x1b[0m";
+
+        let mut first_line = Vec::with_capacity(self.message().len());
+        first_line.extend_from_slice(self.message().as_ref());
+        first_line.extend_from_slice(b" (");
+        if highlight {
+            first_line.extend_from_slice(HIGHLIGHT_BEGIN);
+        }
+        first_line.extend_from_slice(self.name().as_bytes());
+        if highlight {
+            first_line.extend_from_slice(HIGHLIGHT_END);
+        }
+        first_line.extend_from_slice(b")\n");
+
+        let backtrace = self.backtrace(interp).unwrap_or_default();
+        let mut message = Vec::new();
+        match order {
+            BacktraceOrder::Top => {
+                message.extend_from_slice(&first_line);
+                for frame in &backtrace {
+                    message.extend_from_slice(b"\tfrom ");
+                    message.extend_from_slice(frame);
+                    message.push(b'\n');
+                }
+            }
+            BacktraceOrder::Bottom => {
+                for frame in backtrace.iter().rev() {
+                    message.extend_from_slice(b"\tfrom ");
+                    message.extend_from_slice(frame);
+                    message.push(b'\n');
+                }
+                message.extend_from_slice(&first_line);
+            }
+        }
+        message
+    }
+}
+
 impl RubyException for Exception {
     fn message(&self) -> Cow<'_, [u8]> {
         self.0.message()
@@ -86,6 +195,90 @@ where
     hint::unreachable_unchecked()
 }
 
+/// Execute a native method body and convert a Rust panic into a Ruby `fatal`
+/// exception instead of unwinding across the FFI boundary.
+///
+/// Unwinding out of an `extern "C"` function is undefined behavior. Native
+/// methods written in Rust should wrap their bodies in this function so an
+/// accidental panic -- a slice index out of bounds, an `unwrap` on `None` --
+/// surfaces in the VM as a raised [`Fatal`] exception carrying the panic
+/// message rather than aborting the process.
+///
+/// # Safety
+///
+/// This function must be called from an mruby native method entrypoint with a
+/// valid `mrb`. If `body` panics, this function unwinds the stack with
+/// `longjmp` via [`raise`], so callers must not hold non-[`Copy`] values
+/// across the call.
+pub unsafe fn catch_panic<F>(mrb: *mut sys::mrb_state, body: F) -> sys::mrb_value
+where
+    F: FnOnce() -> sys::mrb_value,
+{
+    match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(value) => value,
+        Err(panic) => {
+            let mut message = String::from("Rust panic: ");
+            if let Some(panic_message) = panic.downcast_ref::<&'static str>() {
+                message.push_str(panic_message);
+            } else if let Some(panic_message) = panic.downcast_ref::<String>() {
+                message.push_str(panic_message);
+            } else {
+                message.push_str("panic in native method");
+            }
+            // The panic payload may be an arbitrary non-`Copy` type. Drop it
+            // before `raise` unwinds the stack with `longjmp`.
+            drop(panic);
+            let mut interp = unwrap_interpreter!(mrb);
+            let guard = Guard::new(&mut interp);
+            raise(guard, Fatal::from(message))
+        }
+    }
+}
+
+impl Artichoke {
+    /// Build an [`Exception`] from an exception class referenced by name,
+    /// optionally with a message, mirroring the `raise Class` and
+    /// `raise Class, message` forms of `Kernel#raise`.
+    ///
+    /// The returned error can be propagated out of native code with `?` or
+    /// raised at an FFI entrypoint with [`raise`].
+    ///
+    /// If `class_name` does not resolve to a defined class, a `NameError` is
+    /// returned instead.
+    pub fn raise(&mut self, class_name: &str, message: Option<&[u8]>) -> Exception {
+        let class = match self.class_of_name(class_name) {
+            Ok(Some(class)) => class,
+            Ok(None) => {
+                let message = format!("uninitialized constant {}", class_name);
+                return NameError::from(message).into();
+            }
+            Err(exception) => return exception,
+        };
+        let result = if let Some(message) = message {
+            let message = self.convert_mut(message);
+            class.funcall(self, "new", &[message], None)
+        } else {
+            class.funcall(self, "new", &[], None)
+        };
+        match result {
+            Ok(instance) => self.raise_value(instance),
+            Err(exception) => exception,
+        }
+    }
+
+    /// Build an [`Exception`] from a pre-built Ruby exception instance,
+    /// mirroring the `raise exception` form of `Kernel#raise`.
+    ///
+    /// The instance's class name and message are extracted with funcalls and
+    /// the error currently in flight, if any, is recorded as its `cause`.
+    pub fn raise_value(&mut self, exception: Value) -> Exception {
+        match exception_handler::last_error(self, exception) {
+            Ok(exception) => exception,
+            Err(exception) => exception,
+        }
+    }
+}
+
 /// Polymorphic exception type that corresponds to Ruby's `Exception`.
 ///
 /// All types that implement `RubyException` can be raised with
@@ -197,3 +390,120 @@ impl From<CaughtException> for Exception {
         Self(Box::new(exc))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BacktraceOrder;
+    use crate::test::prelude::*;
+
+    #[test]
+    fn raise_by_class_name() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.raise("ArgumentError", None);
+        assert_eq!("ArgumentError", err.name().as_ref());
+        assert_eq!(&b"ArgumentError"[..], err.message().as_ref());
+        let err = interp.raise("ArgumentError", Some(b"wrong number of arguments"));
+        assert_eq!("ArgumentError", err.name().as_ref());
+        assert_eq!(&b"wrong number of arguments"[..], err.message().as_ref());
+    }
+
+    #[test]
+    fn raise_by_unknown_class_name_is_a_name_error() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.raise("NotDefinedAnywhere", None);
+        assert_eq!("NameError", err.name().as_ref());
+        assert_eq!(
+            &b"uninitialized constant NotDefinedAnywhere"[..],
+            err.message().as_ref()
+        );
+    }
+
+    #[test]
+    fn raise_value_reraises_an_existing_instance() {
+        let mut interp = crate::interpreter().unwrap();
+        let instance = interp.eval(b"TypeError.new('nope')").unwrap();
+        let err = interp.raise_value(instance);
+        assert_eq!("TypeError", err.name().as_ref());
+        assert_eq!(&b"nope"[..], err.message().as_ref());
+    }
+
+    #[test]
+    fn full_message_renders_message_class_and_backtrace() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.eval(b"raise ArgumentError, 'whoops'").unwrap_err();
+        let message = err.full_message(&mut interp, false, BacktraceOrder::Top);
+        assert_eq!(
+            &b"whoops (ArgumentError)\n\tfrom (eval):1\n"[..],
+            message.as_slice()
+        );
+        let message = err.full_message(&mut interp, false, BacktraceOrder::Bottom);
+        assert_eq!(
+            &b"\tfrom (eval):1\nwhoops (ArgumentError)\n"[..],
+            message.as_slice()
+        );
+    }
+
+    #[test]
+    fn full_message_highlights_class_name() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.eval(b"raise ArgumentError, 'whoops'").unwrap_err();
+        let message = err.full_message(&mut interp, true, BacktraceOrder::Top);
+        assert_eq!(
+            &b"whoops (\x1b[1mArgumentError\x1b[0m)\n\tfrom (eval):1\n"[..],
+            message.as_slice()
+        );
+    }
+
+    #[test]
+    fn full_message_without_backtrace() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = Exception::from(ArgumentError::from("never raised"));
+        let message = err.full_message(&mut interp, false, BacktraceOrder::Top);
+        assert_eq!(&b"never raised (ArgumentError)\n"[..], message.as_slice());
+        let message = err.full_message(&mut interp, false, BacktraceOrder::Bottom);
+        assert_eq!(&b"never raised (ArgumentError)\n"[..], message.as_slice());
+    }
+
+    #[test]
+    fn backtrace_of_raised_exception() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.eval(b"raise ArgumentError, 'whoops'").unwrap_err();
+        let backtrace = err.backtrace(&mut interp).unwrap();
+        assert_eq!(backtrace, vec![Vec::from(&b"(eval):1"[..])]);
+    }
+
+    #[test]
+    fn backtrace_of_fresh_exception_is_none() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = Exception::from(ArgumentError::from("never raised"));
+        assert!(err.backtrace(&mut interp).is_none());
+    }
+
+    struct Panicker;
+
+    unsafe extern "C" fn panicking_method(
+        mrb: *mut sys::mrb_state,
+        _slf: sys::mrb_value,
+    ) -> sys::mrb_value {
+        exception::catch_panic(mrb, || panic!("oh no!"))
+    }
+
+    #[test]
+    fn rust_panic_in_native_method_raises_fatal() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = class::Spec::new("Panicker", None, None).unwrap();
+        class::Builder::for_spec(&mut interp, &spec)
+            .add_self_method("panic", panicking_method, sys::mrb_args_none())
+            .unwrap()
+            .define()
+            .unwrap();
+        interp.def_class::<Panicker>(spec).unwrap();
+
+        let err = interp.eval(b"Panicker.panic").unwrap_err();
+        assert_eq!("fatal", err.name().as_ref());
+        assert_eq!(&b"Rust panic: oh no!"[..], err.message().as_ref());
+        // The interpreter remains usable after the panic is converted.
+        let result = interp.eval(b"2 + 2").unwrap();
+        assert_eq!(4, result.try_into::<Int>(&interp).unwrap());
+    }
+}
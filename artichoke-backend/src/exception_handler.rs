@@ -1,9 +1,18 @@
-use crate::core::Value as _;
+use crate::core::{Globals, Intern, TryConvert, Value as _};
 use crate::exception::{CaughtException, Exception};
 use crate::gc::MrbGarbageCollection;
+use crate::sys;
 use crate::value::Value;
 use crate::Artichoke;
 
+/// Global variable that tracks the error currently in flight.
+///
+/// mruby does not implement `Exception#cause`, so Artichoke records the last
+/// extracted error in the `$!` global and attaches it as the `cause` of the
+/// next error raised while it is still in flight. Storing the error in a
+/// global also roots it in the garbage collector.
+pub const ERRINFO: &[u8] = b"$!";
+
 /// Transform a `Exception` Ruby `Value` into an [`Exception`].
 ///
 /// # Errors
@@ -43,6 +52,26 @@ pub fn last_error(interp: &mut Artichoke, exception: Value) -> Result<Exception,
     let message = exception.funcall(&mut arena, "message", &[], None)?;
     let message = message.try_into_mut::<&[u8]>(&mut arena)?;
 
+    // If another error was in flight when this one was raised, record it as
+    // this error's `cause`. `Exception#cause` reads the `@__cause__` ivar.
+    let in_flight = arena.get_global_variable(ERRINFO)?;
+    if let Some(cause) = in_flight.filter(|cause| !cause.is_nil()) {
+        let sym = arena.intern_bytes(&b"@__cause__"[..])?;
+        let already_chained = unsafe {
+            arena.with_ffi_boundary(|mrb| sys::mrb_iv_defined(mrb, exception.inner(), sym.into()))?
+        };
+        let same = exception.funcall(&mut arena, "equal?", &[cause], None)?;
+        let same = arena.try_convert(same)?;
+        if !already_chained && !same {
+            unsafe {
+                arena.with_ffi_boundary(|mrb| {
+                    sys::mrb_iv_set(mrb, exception.inner(), sym.into(), cause.inner());
+                })?;
+            }
+        }
+    }
+    arena.set_global_variable(ERRINFO, &exception)?;
+
     let exception = CaughtException::new(exception, String::from(classname), message.to_vec());
     debug!("Extracted exception from interpreter: {}", exception);
     Ok(Exception::from(exception))
@@ -75,6 +104,36 @@ mod tests {
         assert_eq!(None, err.vm_backtrace(&mut interp));
     }
 
+    #[test]
+    fn error_raised_while_another_is_in_flight_records_cause() {
+        let mut interp = crate::interpreter().unwrap();
+        let first = interp.eval(b"raise 'first'").unwrap_err();
+        assert!(first.cause(&mut interp).is_none());
+        // A new error raised while `first` is in flight records it as the
+        // new error's `cause`.
+        let second = interp.eval(b"raise 'second'").unwrap_err();
+        let cause = second.cause(&mut interp).unwrap();
+        let message = cause.funcall(&mut interp, "message", &[], None).unwrap();
+        let message = message.try_into_mut::<&[u8]>(&mut interp).unwrap();
+        assert_eq!(&b"first"[..], message);
+        // The chain is two links deep and observable from Ruby.
+        let second = Value::from(second.as_mrb_value(&mut interp).unwrap());
+        interp.set_global_variable(&b"$second"[..], &second).unwrap();
+        let result = interp
+            .eval(b"$second.cause.message == 'first' && $second.cause.cause.nil?")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn successful_eval_clears_in_flight_error() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp.eval(b"raise 'first'").unwrap_err();
+        let _ = interp.eval(b"nil").unwrap();
+        let err = interp.eval(b"raise 'second'").unwrap_err();
+        assert!(err.cause(&mut interp).is_none());
+    }
+
     #[test]
     fn raise_does_not_panic_or_segfault() {
         let mut interp = crate::interpreter().expect("init");
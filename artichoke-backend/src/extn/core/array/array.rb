@@ -872,7 +872,10 @@ class Array
       end
       idx += 1
     end
-    self[0, len] = ar if modified
+    return nil unless modified
+
+    self[0, len] = ar
+    self
   end
 
   def include?(object)
@@ -1197,8 +1200,28 @@ class Array
     replace(rotate(count))
   end
 
-  def sample(*_args)
-    raise NotImplementedError, 'TODO implement in Rust'
+  def sample(*args)
+    opts = args.last.is_a?(Hash) ? args.pop : {}
+    raise ArgumentError, "wrong number of arguments (given #{args.length}, expected 0..1)" if args.length > 1
+
+    rng = opts[:random] || Random::DEFAULT
+    if args.empty?
+      return nil if empty?
+
+      return self[rng.rand(length)]
+    end
+
+    count = args.first
+    raise TypeError, "no implicit conversion of #{count.class} into Integer" unless count.respond_to?(:to_int)
+
+    count = count.to_int
+    raise ArgumentError, 'negative sample number' if count.negative?
+
+    count = length if count > length
+    pool = dup
+    result = []
+    result << pool.delete_at(rng.rand(pool.length)) while result.length < count
+    result
   end
 
   def select(&block)
@@ -1268,12 +1291,25 @@ class Array
     result
   end
 
-  def shuffle(_rng = (not_set = true)) # rubocop:disable Lint/UselessAssignment
-    raise NotImplementedError, 'TODO implement in Rust'
+  def shuffle(*args)
+    dup.tap { |ary| ary.shuffle!(*args) }
   end
 
-  def shuffle!(_rng = (not_set = true)) # rubocop:disable Lint/UselessAssignment
-    raise NotImplementedError, 'TODO implement in Rust'
+  def shuffle!(*args)
+    raise FrozenError, "can't modify frozen Array" if frozen?
+
+    opts = args.last.is_a?(Hash) ? args.pop : {}
+    raise ArgumentError, "wrong number of arguments (given #{args.length}, expected 0)" unless args.empty?
+
+    rng = opts[:random] || Random::DEFAULT
+    # Fisher-Yates shuffle.
+    idx = length - 1
+    while idx.positive?
+      other = rng.rand(idx + 1)
+      self[idx], self[other] = self[other], self[idx]
+      idx -= 1
+    end
+    self
   end
 
   def slice!(*args)
@@ -1322,6 +1358,10 @@ class Array
     end
   end
 
+  # Like MRI, `Array#sort` makes no guarantee about the relative order of
+  # elements that compare equal, even though the current merge sort happens to
+  # preserve it. Callers that rely on stability should use `sort_stable` (or
+  # `sort_by`, which breaks ties by element index).
   def sort(&block)
     return dup if length <= 1
 
@@ -1376,6 +1416,27 @@ class Array
     self
   end
 
+  # A guaranteed-stable `sort`: elements that compare equal keep their
+  # relative order from the receiver. Equal elements are disambiguated by
+  # their index, so stability holds regardless of the underlying sort
+  # algorithm.
+  def sort_stable(&block)
+    return dup if length <= 1
+
+    block ||= ->(a, b) { a <=> b }
+    pairs = []
+    idx = 0
+    while idx < length
+      pairs << [self[idx], idx]
+      idx += 1
+    end
+    pairs = pairs.sort do |left, right|
+      cmp = block.call(left[0], right[0])
+      cmp.zero? ? left[1] <=> right[1] : cmp
+    end
+    pairs.map { |pair| pair[0] }
+  end
+
   def sort_by!(&block)
     raise FrozenError, "can't modify frozen Array" if frozen?
     return to_enum(:sort_by) unless block
@@ -333,3 +333,180 @@ impl Array {
         self.0.reverse();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn with_capacity_and_push_build_a_ruby_array() {
+        let mut interp = crate::interpreter().unwrap();
+        let mut ary = Array::with_capacity(3);
+        for item in &[1, 2, 3] {
+            ary.push(interp.convert(*item));
+        }
+        let value = Array::alloc_value(ary, interp).unwrap();
+        let result = value.funcall(&mut interp, "inspect", &[], None).unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("[1, 2, 3]", result);
+    }
+
+    #[test]
+    fn flatten_bang_returns_self_when_modified() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"ary = [1, [2, 3]]; ary.flatten!.equal?(ary)")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn flatten_bang_returns_nil_when_unchanged() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"[1, 2, 3].flatten!").unwrap();
+        assert!(result.is_nil());
+    }
+
+    #[test]
+    fn compact_bang_returns_self_when_modified() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"ary = [1, nil, 2]; ary.compact!.equal?(ary)")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn compact_bang_returns_nil_when_unchanged() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"[1, 2].compact!").unwrap();
+        assert!(result.is_nil());
+    }
+
+    #[test]
+    fn uniq_bang_returns_self_when_modified() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"ary = [1, 1, 2]; ary.uniq!.equal?(ary)")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn uniq_bang_returns_nil_when_unchanged() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"[1, 2, 3].uniq!").unwrap();
+        assert!(result.is_nil());
+    }
+
+    #[test]
+    fn mutating_frozen_array_raises_frozen_error() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.eval(b"[1, 2].freeze.clear").unwrap_err();
+        assert_eq!("FrozenError", err.name().as_ref());
+        assert_eq!(&b"can't modify frozen Array"[..], err.message().as_ref());
+    }
+
+    #[test]
+    fn sort_by_is_stable_for_equal_keys() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(
+                b"ary = [[1, :a], [0, :b], [1, :c], [0, :d]]; ary.sort_by { |pair| pair[0] } == [[0, :b], [0, :d], [1, :a], [1, :c]]",
+            )
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn sort_stable_preserves_order_of_equal_elements() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(
+                b"ary = [[1, :a], [0, :b], [1, :c], [0, :d]]; sorted = ary.sort_stable { |l, r| l[0] <=> r[0] }; sorted == [[0, :b], [0, :d], [1, :a], [1, :c]]",
+            )
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"[3, 1, 2].sort_stable == [1, 2, 3]")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[cfg(feature = "core-random")]
+    #[test]
+    fn sample_is_reproducible_after_srand() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(
+                b"ary = (1..100).to_a; Random.srand(42); first = ary.sample; Random.srand(42); first == ary.sample",
+            )
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[cfg(feature = "core-random")]
+    #[test]
+    fn sample_count_never_exceeds_array_length() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"ary = (1..5).to_a; ary.sample(10).sort == ary")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"ary = (1..5).to_a; sampled = ary.sample(3); sampled.length == 3 && sampled.uniq.length == 3")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"[1, 2, 3].sample(0) == []").unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[cfg(feature = "core-random")]
+    #[test]
+    fn sample_accepts_random_keyword() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(
+                b"ary = (1..100).to_a; ary.sample(random: Random.new(7)) == ary.sample(random: Random.new(7))",
+            )
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[cfg(feature = "core-random")]
+    #[test]
+    fn shuffle_is_reproducible_after_srand() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(
+                b"ary = (1..50).to_a; Random.srand(99); first = ary.shuffle; Random.srand(99); first == ary.shuffle",
+            )
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"ary = (1..50).to_a; ary.shuffle.sort == ary")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[cfg(feature = "core-random")]
+    #[test]
+    fn shuffle_bang_mutates_receiver_and_rejects_frozen() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"ary = (1..50).to_a; ary.shuffle!.equal?(ary)")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let err = interp.eval(b"[1, 2, 3].freeze.shuffle!").unwrap_err();
+        assert_eq!("FrozenError", err.name().as_ref());
+    }
+
+    #[test]
+    fn uniq_bang_uses_block_result_for_uniqueness() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"[1, 3, 2, 4].uniq! { |x| x % 2 } == [1, 2]")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+}
@@ -3,9 +3,7 @@ use crate::extn::prelude::*;
 use crate::gc::{MrbGarbageCollection, State as GcState};
 
 pub fn clear(interp: &mut Artichoke, mut ary: Value) -> Result<Value, Exception> {
-    if ary.is_frozen(interp) {
-        return Err(FrozenError::from("can't modify frozen Array").into());
-    }
+    ary.ensure_not_frozen(interp)?;
     let mut array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
     array.clear();
     Ok(ary)
@@ -29,9 +27,7 @@ pub fn element_assignment(
     second: Value,
     third: Option<Value>,
 ) -> Result<Value, Exception> {
-    if ary.is_frozen(interp) {
-        return Err(FrozenError::from("can't modify frozen Array").into());
-    }
+    ary.ensure_not_frozen(interp)?;
     // TODO: properly handle self-referential sets.
     if ary == first || ary == second || Some(ary) == third {
         return Ok(Value::nil());
@@ -49,9 +45,7 @@ pub fn element_assignment(
 }
 
 pub fn pop(interp: &mut Artichoke, mut ary: Value) -> Result<Value, Exception> {
-    if ary.is_frozen(interp) {
-        return Err(FrozenError::from("can't modify frozen Array").into());
-    }
+    ary.ensure_not_frozen(interp)?;
     let mut array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
     let result = array.pop();
     Ok(interp.convert(result))
@@ -62,9 +56,7 @@ pub fn concat(
     mut ary: Value,
     other: Option<Value>,
 ) -> Result<Value, Exception> {
-    if ary.is_frozen(interp) {
-        return Err(FrozenError::from("can't modify frozen Array").into());
-    }
+    ary.ensure_not_frozen(interp)?;
     if let Some(other) = other {
         let mut array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
         array.concat(interp, other)?;
@@ -73,18 +65,14 @@ pub fn concat(
 }
 
 pub fn push(interp: &mut Artichoke, mut ary: Value, value: Value) -> Result<Value, Exception> {
-    if ary.is_frozen(interp) {
-        return Err(FrozenError::from("can't modify frozen Array").into());
-    }
+    ary.ensure_not_frozen(interp)?;
     let mut array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
     array.push(value);
     Ok(ary)
 }
 
 pub fn reverse_bang(interp: &mut Artichoke, mut ary: Value) -> Result<Value, Exception> {
-    if ary.is_frozen(interp) {
-        return Err(FrozenError::from("can't modify frozen Array").into());
-    }
+    ary.ensure_not_frozen(interp)?;
     let mut array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
     array.reverse();
     Ok(ary)
@@ -522,6 +522,8 @@ module Enumerable
     map(&:itself).sort(&block)
   end
 
+  # Unlike `sort`, `sort_by` is stable: keys that compare equal are broken by
+  # element index, so equal-keyed elements keep their original order.
   def sort_by(&block)
     return to_enum :sort_by unless block
 
@@ -15,3 +15,37 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
 
 #[derive(Debug)]
 pub struct Enumerator;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn lazy_first_terminates_on_endless_range() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"(1..Float::INFINITY).lazy.map { |x| x * 2 }.first(3)")
+            .unwrap();
+        let result = result.try_into_mut::<Vec<Int>>(&mut interp).unwrap();
+        assert_eq!(result, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn lazy_select_take_does_not_materialize_source() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"(1..Float::INFINITY).lazy.select(&:even?).take(2).to_a")
+            .unwrap();
+        let result = result.try_into_mut::<Vec<Int>>(&mut interp).unwrap();
+        assert_eq!(result, vec![2, 4]);
+    }
+
+    #[test]
+    fn lazy_map_returns_lazy_enumerator() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"(1..Float::INFINITY).lazy.map { |x| x }.is_a?(Enumerator::Lazy)")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+}
@@ -1,5 +1,15 @@
 # frozen_string_literal: true
 
+class Exception
+  # The error that was in flight when this exception was raised.
+  #
+  # The interpreter records the cause in the `@__cause__` ivar when an
+  # exception is raised while another error is being handled.
+  def cause
+    @__cause__
+  end
+end
+
 class KeyError
   attr_reader :key
   attr_reader :receiver
@@ -11,6 +21,15 @@ class KeyError
   end
 end
 
+class FrozenError
+  attr_reader :receiver
+
+  def initialize(message = nil, receiver: nil)
+    @receiver = receiver
+    super(message)
+  end
+end
+
 class NameError
   attr_reader :name
 
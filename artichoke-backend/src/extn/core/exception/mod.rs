@@ -34,6 +34,7 @@
 //!   - `ThreadError`
 //!   - `TypeError`
 //!   - `ZeroDivisionError`
+//! - `StepLimitExceeded` -- Artichoke-specific, raised by `eval_limited`
 //! - `SystemExit`
 //! - `SystemStackError`
 //! - `fatal` -- impossible to rescue
@@ -232,6 +233,15 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .define()?;
     interp.def_class::<ZeroDivisionError>(zerodivision_spec)?;
 
+    // Artichoke-specific. Raised when an `eval_limited` call exhausts its
+    // step budget. Subclasses `Exception` directly, like `SystemStackError`
+    // and `NoMemoryError`, so a bare `rescue` does not swallow it.
+    let steplimit_spec = class::Spec::new("StepLimitExceeded", None, None)?;
+    class::Builder::for_spec(interp, &steplimit_spec)
+        .with_super_class::<Exception, _>("Exception")?
+        .define()?;
+    interp.def_class::<StepLimitExceeded>(steplimit_spec)?;
+
     let systemexit_spec = class::Spec::new("SystemExit", None, None)?;
     class::Builder::for_spec(interp, &systemexit_spec)
         .with_super_class::<Exception, _>("Exception")?
@@ -415,6 +425,8 @@ ruby_exception_impl!(SystemCallError);
 ruby_exception_impl!(ThreadError);
 ruby_exception_impl!(TypeError);
 ruby_exception_impl!(ZeroDivisionError);
+// Artichoke-specific: raised when `eval_limited` exhausts its step budget.
+ruby_exception_impl!(StepLimitExceeded);
 ruby_exception_impl!(SystemExit);
 ruby_exception_impl!(SystemStackError);
 // Fatal interpreter error. Impossible to rescue.
@@ -460,4 +472,184 @@ mod tests {
             err.vm_backtrace(&mut interp)
         );
     }
+
+    #[test]
+    fn ensure_runs_on_all_exit_paths() {
+        let mut interp = crate::interpreter().expect("init");
+        let code = br#"
+$cleanups = []
+
+def normal
+  'value'
+ensure
+  $cleanups << :normal
+end
+normal
+
+begin
+  begin
+    raise 'boom'
+  ensure
+    $cleanups << :raised
+  end
+rescue RuntimeError
+  nil
+end
+
+def early_return
+  return :early
+ensure
+  $cleanups << :returned
+end
+early_return
+
+catch(:tag) do
+  begin
+    throw :tag
+  ensure
+    $cleanups << :thrown
+  end
+end
+
+$cleanups
+"#;
+        let result = interp.eval(code).unwrap();
+        let inspect = result.funcall(&mut interp, "inspect", &[], None).unwrap();
+        let inspect = inspect.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("[:normal, :raised, :returned, :thrown]", inspect);
+    }
+
+    #[test]
+    fn retry_reruns_begin_block() {
+        let mut interp = crate::interpreter().expect("init");
+        let code = br#"
+attempts = 0
+begin
+  attempts += 1
+  raise 'flaky' if attempts < 3
+  attempts
+rescue RuntimeError
+  retry
+end
+"#;
+        // `retry` re-runs the `begin` block; this counter succeeds on the
+        // third attempt. Loop termination is up to the rescue clause's own
+        // logic -- the VM does not cap retries.
+        let result = interp.eval(code).unwrap();
+        assert_eq!(3, result.try_into::<Int>(&interp).unwrap());
+    }
+
+    #[test]
+    fn retry_outside_rescue_is_an_error() {
+        let mut interp = crate::interpreter().expect("init");
+        let err = interp.eval(b"retry").unwrap_err();
+        assert_eq!("LocalJumpError", err.name().as_ref());
+        assert_eq!(&b"unexpected retry"[..], err.message().as_ref());
+    }
+
+    #[test]
+    fn frozen_mutation_raises_frozen_error() {
+        let mut interp = crate::interpreter().expect("init");
+        let err = interp.eval(b"'x'.freeze << 'y'").unwrap_err();
+        assert_eq!("FrozenError", err.name().as_ref());
+        let result = interp
+            .eval(b"begin; 'x'.freeze << 'y'; rescue FrozenError => e; e.receiver.nil?; end")
+            .unwrap();
+        // The VM raise path does not capture the receiver; the accessor is
+        // still callable and returns `nil`.
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"begin; raise FrozenError.new('nope', receiver: :obj); rescue FrozenError => e; e.receiver == :obj; end")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    mod attributes {
+        use std::borrow::Cow;
+        use std::error;
+        use std::fmt;
+
+        use crate::test::prelude::*;
+
+        // A `KeyError`-like exception that carries the missing key and
+        // attaches it to the raised exception value as the `@key` instance
+        // variable.
+        #[derive(Debug, Clone)]
+        struct MissingKey {
+            key: String,
+        }
+
+        impl fmt::Display for MissingKey {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "KeyError (key not found: {})", self.key)
+            }
+        }
+
+        impl error::Error for MissingKey {}
+
+        impl RubyException for MissingKey {
+            fn message(&self) -> Cow<'_, [u8]> {
+                let mut message = b"key not found: ".to_vec();
+                message.extend_from_slice(self.key.as_bytes());
+                message.into()
+            }
+
+            fn name(&self) -> Cow<'_, str> {
+                "KeyError".into()
+            }
+
+            fn vm_backtrace(&self, interp: &mut Artichoke) -> Option<Vec<Vec<u8>>> {
+                let _ = interp;
+                None
+            }
+
+            fn as_mrb_value(&self, interp: &mut Artichoke) -> Option<sys::mrb_value> {
+                let message = interp.convert_mut(self.message());
+                let value = interp.new_instance::<KeyError>(&[message]).ok().flatten()?;
+                let key = interp.convert_mut(self.key.as_str());
+                value.set_instance_variable(interp, "@key", key).ok()?;
+                Some(value.inner())
+            }
+        }
+
+        struct Run;
+
+        unsafe extern "C" fn run_run(
+            mrb: *mut sys::mrb_state,
+            _slf: sys::mrb_value,
+        ) -> sys::mrb_value {
+            let mut interp = unwrap_interpreter!(mrb);
+            let guard = Guard::new(&mut interp);
+            let exc = MissingKey {
+                key: String::from("artichoke"),
+            };
+            exception::raise(guard, exc)
+        }
+
+        impl File for Run {
+            type Artichoke = Artichoke;
+
+            type Error = Exception;
+
+            fn require(interp: &mut Artichoke) -> Result<(), Self::Error> {
+                let spec = class::Spec::new("Run", None, None).unwrap();
+                class::Builder::for_spec(interp, &spec)
+                    .add_self_method("run", run_run, sys::mrb_args_none())?
+                    .define()?;
+                interp.def_class::<Self>(spec)?;
+                Ok(())
+            }
+        }
+
+        #[test]
+        fn custom_attribute_is_readable_from_rescue() {
+            let mut interp = crate::interpreter().expect("init");
+            Run::require(&mut interp).unwrap();
+            let result = interp
+                .eval(b"begin; Run.run; rescue KeyError => e; e.instance_variable_get(:@key); end")
+                .unwrap();
+            let key = result.try_into_mut::<&str>(&mut interp).unwrap();
+            assert_eq!("artichoke", key);
+        }
+    }
 }
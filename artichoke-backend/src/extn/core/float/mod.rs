@@ -227,3 +227,49 @@ impl Float {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn nan_is_only_true_for_nan() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"[Float::NAN.nan?, Float::INFINITY.nan?, 1.5.nan?, 0.0.nan?] == [true, false, false, false]")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn infinite_reports_sign_or_nil() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"[Float::INFINITY.infinite?, (-Float::INFINITY).infinite?, 1.5.infinite?, Float::NAN.infinite?] == [1, -1, nil, nil]")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn finite_is_false_for_nan_and_infinities() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"[1.5.finite?, 0.0.finite?, Float::INFINITY.finite?, (-Float::INFINITY).finite?, Float::NAN.finite?] == [true, true, false, false, false]")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn predicates_interoperate_with_rust_conversions() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.convert_mut(Fp::NAN);
+        let result = value.funcall(&mut interp, "nan?", &[], None).unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let value = interp.convert_mut(Fp::INFINITY);
+        let result = value.funcall(&mut interp, "infinite?", &[], None).unwrap();
+        assert_eq!(1, result.try_into::<Int>(&interp).unwrap());
+        let value = interp.eval(b"-1.0 / 0.0").unwrap();
+        let value = value.try_into::<Fp>(&interp).unwrap();
+        assert!(value.is_infinite() && value < 0.0);
+    }
+}
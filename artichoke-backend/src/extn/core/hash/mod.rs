@@ -13,3 +13,39 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
 
 #[derive(Debug)]
 pub struct Hash;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn default_value_returned_for_missing_key() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"h = Hash.new(0); h[:missing]").unwrap();
+        assert_eq!(result.try_into::<Int>(&interp).unwrap(), 0);
+        // The default is returned without inserting the key.
+        let result = interp.eval(b"h.key?(:missing)").unwrap();
+        assert!(!result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn default_proc_computes_and_stores_values() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"h = Hash.new { |hash, key| hash[key] = [] }; h[:list] << 1; h[:list]")
+            .unwrap();
+        let result = result.try_into_mut::<Vec<Int>>(&mut interp).unwrap();
+        assert_eq!(result, vec![1]);
+        let result = interp.eval(b"h.key?(:list)").unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn fetch_raises_key_error_despite_default() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp
+            .eval(b"Hash.new(0).fetch(:missing)")
+            .unwrap_err();
+        assert_eq!("KeyError", err.name().as_ref());
+    }
+}
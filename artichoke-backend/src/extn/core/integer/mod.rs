@@ -142,7 +142,11 @@ impl Integer {
     #[inline]
     pub fn bit(self, bit: Int) -> Result<Self, Exception> {
         if let Ok(bit) = u32::try_from(bit) {
-            Ok(self.as_i64().checked_shr(bit).map_or(0, |v| v & 1).into())
+            // Negative numbers are infinitely sign extended, so bits beyond
+            // the width of `Int` read as the sign bit.
+            let sign = self.as_i64() >> (Self::size() * 8 - 1);
+            let shifted = self.as_i64().checked_shr(bit).unwrap_or(sign);
+            Ok(Self(shifted & 1))
         } else {
             Ok(Self(0))
         }
@@ -183,6 +187,20 @@ impl Integer {
         }
     }
 
+    /// The number of bits in the two's-complement representation of `self`,
+    /// excluding the sign.
+    ///
+    /// Negative numbers are infinitely sign extended, so their leading one
+    /// bits do not count toward the length, mirroring MRI.
+    #[inline]
+    #[must_use]
+    pub fn bit_length(self) -> Int {
+        // For negative numbers the leading ones are sign extension, so count
+        // the significant bits of the complement instead.
+        let value = if self.0 < 0 { !self.0 } else { self.0 };
+        Int::from(Self::size() as u32 * 8 - value.leading_zeros())
+    }
+
     #[must_use]
     pub const fn size() -> usize {
         mem::size_of::<Int>()
@@ -195,6 +213,35 @@ mod tests {
 
     use crate::test::prelude::*;
 
+    #[test]
+    fn bit_length_of_positive_and_negative_integers() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"[0, 1, 255, 256, 65535].map(&:bit_length) == [0, 1, 8, 9, 16]")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"[-1, -2, -256, -257].map(&:bit_length) == [0, 1, 8, 9]")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn element_reference_reads_bits_with_sign_extension() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"[5[0], 5[1], 5[2], 5[100]] == [1, 0, 1, 0]")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        // Negative numbers are infinitely sign extended.
+        let result = interp
+            .eval(b"[(-1)[0], (-1)[100], (-2)[0], (-2)[1], (-2)[100]] == [1, 1, 0, 1, 1]")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"[7[-1], 0[100]] == [0, 0]").unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
     #[quickcheck]
     fn positive_integer_division_vm_opcode(x: u8, y: u8) -> bool {
         let mut interp = crate::interpreter().expect("init");
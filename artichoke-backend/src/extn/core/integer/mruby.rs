@@ -14,6 +14,11 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
             artichoke_integer_element_reference,
             sys::mrb_args_req(1),
         )?
+        .add_method(
+            "bit_length",
+            artichoke_integer_bit_length,
+            sys::mrb_args_none(),
+        )?
         .add_method("/", artichoke_integer_div, sys::mrb_args_req(1))?
         .add_method("size", artichoke_integer_size, sys::mrb_args_none())?
         .define()?;
@@ -55,6 +60,21 @@ unsafe extern "C" fn artichoke_integer_element_reference(
     }
 }
 
+unsafe extern "C" fn artichoke_integer_bit_length(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let value = Value::from(slf);
+    let result = trampoline::bit_length(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn artichoke_integer_div(
     mrb: *mut sys::mrb_state,
     slf: sys::mrb_value,
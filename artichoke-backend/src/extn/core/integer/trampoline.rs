@@ -22,6 +22,11 @@ pub fn element_reference(
     Ok(interp.convert(bit))
 }
 
+pub fn bit_length(interp: &mut Artichoke, value: Value) -> Result<Value, Exception> {
+    let value = value.try_into::<Integer>(interp)?;
+    Ok(interp.convert(value.bit_length()))
+}
+
 pub fn div(interp: &mut Artichoke, value: Value, denominator: Value) -> Result<Value, Exception> {
     let value = value.try_into::<Integer>(interp)?;
     let quotient = value.div(interp, denominator)?;
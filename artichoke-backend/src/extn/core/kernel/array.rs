@@ -0,0 +1,50 @@
+use crate::extn::prelude::*;
+
+fn gives_type_error(
+    interp: &mut Artichoke,
+    arg: &Value,
+    gave: &Value,
+    method: &str,
+) -> Result<Value, Exception> {
+    let mut message = String::from("can't convert ");
+    message.push_str(arg.pretty_name(interp));
+    message.push_str(" to Array (");
+    message.push_str(arg.pretty_name(interp));
+    message.push('#');
+    message.push_str(method);
+    message.push_str(" gives ");
+    message.push_str(gave.pretty_name(interp));
+    message.push(')');
+    Err(TypeError::from(message).into())
+}
+
+/// Convert an object into an `Array` with MRI's `Kernel#Array` semantics.
+///
+/// `nil` converts to an empty `Array` and an `Array` converts to itself.
+/// Otherwise the object is converted with `#to_ary`, then `#to_a`, when
+/// available; an object that responds to neither is wrapped in a
+/// single-element `Array`.
+pub fn method(interp: &mut Artichoke, arg: Value) -> Result<Value, Exception> {
+    match arg.ruby_type() {
+        Ruby::Array => return Ok(arg),
+        Ruby::Nil => return interp.try_convert_mut(Vec::<Value>::new()),
+        _ => {}
+    }
+    if let Ok(true) = arg.respond_to(interp, "to_ary") {
+        let converted = arg.funcall(interp, "to_ary", &[], None)?;
+        match converted.ruby_type() {
+            Ruby::Array => return Ok(converted),
+            Ruby::Nil => {}
+            _ => return gives_type_error(interp, &arg, &converted, "to_ary"),
+        }
+    }
+    if let Ok(true) = arg.respond_to(interp, "to_a") {
+        let converted = arg.funcall(interp, "to_a", &[], None)?;
+        match converted.ruby_type() {
+            Ruby::Array => return Ok(converted),
+            Ruby::Nil => {}
+            _ => return gives_type_error(interp, &arg, &converted, "to_a"),
+        }
+    }
+    interp.try_convert_mut(vec![arg])
+}
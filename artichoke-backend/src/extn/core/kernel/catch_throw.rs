@@ -0,0 +1,56 @@
+//! Tag-based non-local exit for `Kernel#catch` and `Kernel#throw`.
+//!
+//! `throw` raises an `UncaughtThrowError` carrying the thrown tag and value.
+//! `catch` runs its block and intercepts a throw whose tag is the same object
+//! as its own; non-matching throws propagate to an enclosing `catch` or out of
+//! the VM as an `UncaughtThrowError` with an "uncaught throw" message.
+
+use crate::exception::CaughtException;
+use crate::extn::prelude::*;
+
+pub fn catch(
+    interp: &mut Artichoke,
+    tag: Option<Value>,
+    block: Option<Block>,
+) -> Result<Value, Exception> {
+    let block = block.ok_or_else(|| LocalJumpError::from("no block given"))?;
+    let tag = if let Some(tag) = tag {
+        tag
+    } else {
+        // `catch` without an argument generates a unique tag object which is
+        // only caught by a throw of the object yielded to the block.
+        interp.eval(b"Object.new")?
+    };
+    match block.yield_arg(interp, &tag) {
+        Ok(value) => Ok(value),
+        Err(exception) => {
+            if exception.name() != "UncaughtThrowError" {
+                return Err(exception);
+            }
+            let thrown = if let Some(thrown) = exception.as_mrb_value(interp) {
+                Value::from(thrown)
+            } else {
+                return Err(exception);
+            };
+            let thrown_tag = thrown.funcall(interp, "tag", &[], None)?;
+            // Tags match on identity, not equality.
+            let is_match = thrown_tag.funcall(interp, "equal?", &[tag], None)?;
+            if is_match.try_into::<bool>(interp)? {
+                thrown.funcall(interp, "value", &[], None)
+            } else {
+                Err(exception)
+            }
+        }
+    }
+}
+
+pub fn throw(interp: &mut Artichoke, tag: Value, value: Option<Value>) -> Result<Value, Exception> {
+    let value = value.unwrap_or_else(Value::nil);
+    let exception = interp
+        .new_instance::<UncaughtThrowError>(&[tag, value])?
+        .ok_or_else(|| NotDefinedError::class("UncaughtThrowError"))?;
+    let message = exception.funcall(interp, "message", &[], None)?;
+    let message = message.try_into_mut::<Vec<u8>>(interp)?;
+    let exception = CaughtException::new(exception, String::from("UncaughtThrowError"), message);
+    Err(Exception::from(exception))
+}
@@ -0,0 +1,53 @@
+use bstr::ByteSlice;
+use std::str::{self, FromStr};
+
+use crate::extn::prelude::*;
+
+fn invalid_value(arg: &[u8]) -> Result<Fp, Exception> {
+    let mut message = String::from(r#"invalid value for Float(): ""#);
+    string::format_unicode_debug_into(&mut message, arg)?;
+    message.push('"');
+    Err(ArgumentError::from(message).into())
+}
+
+/// Parse a numeric string into a [`Fp`] with MRI's `Kernel#Float` semantics.
+///
+/// Leading and trailing whitespace is ignored. Underscores are permitted
+/// between digits. Parses that Rust's float parser accepts but MRI does not,
+/// like `"inf"` and `"nan"`, are rejected.
+pub fn method(arg: &[u8]) -> Result<Fp, Exception> {
+    if arg.find_byte(b'\0').is_some() {
+        return invalid_value(arg);
+    }
+    let string = if let Ok(string) = str::from_utf8(arg) {
+        string
+    } else {
+        return invalid_value(arg);
+    };
+    let mut digits = String::with_capacity(string.len());
+    let mut prev = None::<char>;
+    let mut chars = string.trim().chars().peekable();
+    while let Some(current) = chars.next() {
+        // Ignore an embedded underscore (`_`) between digits.
+        if current == '_' {
+            let valid_prev = prev.map_or(false, |prev| prev.is_ascii_digit());
+            let valid_next = chars.peek().map_or(false, char::is_ascii_digit);
+            if valid_prev && valid_next {
+                prev = Some(current);
+                continue;
+            }
+            return invalid_value(arg);
+        }
+        if current.is_ascii_digit() || matches!(current, '+' | '-' | '.' | 'e' | 'E') {
+            digits.push(current);
+            prev = Some(current);
+        } else {
+            return invalid_value(arg);
+        }
+    }
+    if let Ok(float) = Fp::from_str(digits.as_str()) {
+        Ok(float)
+    } else {
+        invalid_value(arg)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::extn::core::array::Array;
+use crate::extn::prelude::*;
+
+fn gives_type_error(
+    interp: &mut Artichoke,
+    arg: &Value,
+    gave: &Value,
+) -> Result<Value, Exception> {
+    let mut message = String::from("can't convert ");
+    message.push_str(arg.pretty_name(interp));
+    message.push_str(" to Hash (");
+    message.push_str(arg.pretty_name(interp));
+    message.push_str("#to_hash gives ");
+    message.push_str(gave.pretty_name(interp));
+    message.push(')');
+    Err(TypeError::from(message).into())
+}
+
+/// Convert an object into a `Hash` with MRI's `Kernel#Hash` semantics.
+///
+/// A `Hash` converts to itself and `nil` and an empty `Array` convert to an
+/// empty `Hash`. Otherwise the object is converted with `#to_hash` when
+/// available. Unlike `Kernel#Array`, objects without a conversion method —
+/// including non-empty `Array`s — raise `TypeError`.
+pub fn method(interp: &mut Artichoke, mut arg: Value) -> Result<Value, Exception> {
+    if let Ruby::Hash = arg.ruby_type() {
+        return Ok(arg);
+    }
+    if let Ok(true) = arg.respond_to(interp, "to_hash") {
+        let converted = arg.funcall(interp, "to_hash", &[], None)?;
+        match converted.ruby_type() {
+            Ruby::Hash => return Ok(converted),
+            Ruby::Nil => {}
+            _ => return gives_type_error(interp, &arg, &converted),
+        }
+    }
+    let is_empty_array = if let Ok(ary) = unsafe { Array::unbox_from_value(&mut arg, interp) } {
+        ary.is_empty()
+    } else {
+        false
+    };
+    if arg.is_nil() || is_empty_array {
+        return Ok(interp.convert_mut(Vec::<(Value, Value)>::new()));
+    }
+    let mut message = String::from("can't convert ");
+    message.push_str(arg.pretty_name(interp));
+    message.push_str(" into Hash");
+    Err(TypeError::from(message).into())
+}
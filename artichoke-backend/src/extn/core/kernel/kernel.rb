@@ -1,65 +1,15 @@
 # frozen_string_literal: true
 
 module Kernel
-  # Setup a catch block and wait for an object to be thrown, the
-  # catch end without catching anything.
-  #
-  # @param [Symbol] tag  tag to catch
-  # @return [void]
-  #
-  # @example
-  #   catch :thing do
-  #     __do_stuff__
-  #     throw :thing
-  #   end
-  def catch(tag = nil)
-    tag = Object.new if tag.nil?
-
-    yield tag
-  rescue UncaughtThrowError => e
-    raise e unless e.tag == tag
-
-    e.value
-  end
-
-  def Array(arg) # rubocop:disable Naming/MethodName
-    return arg if arg.is_a?(Array)
-
-    ret = nil
-    ret = arg.to_ary if arg.respond_to?(:to_ary)
-    classname = arg.class
-
-    unless ret.nil? || ret.is_a?(Array)
-      raise TypeError, "can't convert #{classname} to Array (#{classname}#to_ary gives #{ret.class})"
-    end
-
-    ret = arg.to_a if ret.nil? && arg.respond_to?(:to_a)
+  # `Kernel#catch` and `Kernel#throw` are implemented in Rust and registered
+  # as C methods before this file is evaluated.
 
-    unless ret.nil? || ret.is_a?(Array)
-      raise TypeError, "can't convert #{classname} to Array (#{classname}#to_a gives #{ret.class})"
-    end
-
-    ret.nil? ? [arg] : ret
+  def Float(arg, exception: true) # rubocop:disable Naming/MethodName
+    ::Artichoke::Kernel::Float(arg, exception)
   end
 
-  def Hash(arg) # rubocop:disable Naming/MethodName
-    return arg if arg.is_a?(Hash)
-    return {} if arg.nil? || arg == []
-
-    classname = arg.class
-
-    if arg.respond_to?(:to_hash)
-      ret = arg.to_hash
-
-      return ret if ret.is_a?(Hash)
-
-      raise TypeError, "can't convert #{classname} into Hash" if ret.nil?
-
-      raise TypeError, "can't convert #{classname} to Hash (#{classname}#to_hash gives #{ret.class})"
-    end
-
-    raise TypeError, "can't convert #{classname} into Hash"
-  end
+  # `Kernel#Hash` is implemented in Rust and registered as a C method before
+  # this file is evaluated.
 
   def Integer(arg, base = nil, exception: true) # rubocop:disable Naming/MethodName
     raise ArgumentError, 'base specified for non string value' if base&.positive? && arg.is_a?(Numeric)
@@ -151,22 +101,6 @@ module Kernel
     m
   end
 
-  # Throws an object, uncaught throws will bubble up through other catch blocks.
-  #
-  # @param [Symbol] tag  tag being thrown
-  # @param [Object] value  a value to return to the catch block
-  # @raises [UncaughtThrowError]
-  # @return [void] it will never return normally.
-  #
-  # @example
-  #   catch :ball do
-  #     pitcher.wind_up
-  #     throw :ball
-  #   end
-  def throw(tag, value = nil)
-    raise UncaughtThrowError.new(tag, value)
-  end
-
   def warn(*msg)
     msg.each do |warning|
       warning = warning.to_s
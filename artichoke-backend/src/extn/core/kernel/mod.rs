@@ -1,6 +1,11 @@
+pub mod array;
+pub mod catch_throw;
+pub mod float;
+pub mod hash;
 pub mod integer;
 pub mod mruby;
 pub mod require;
+pub mod sprintf;
 pub mod trampoline;
 
 #[derive(Debug)]
@@ -19,7 +24,466 @@ mod tests {
         assert!(result);
     }
 
+    mod array {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn nil_converts_to_empty_array() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"Array(nil)").unwrap();
+            let result = result.try_into_mut::<Vec<Value>>(&mut interp).unwrap();
+            assert!(result.is_empty());
+        }
+
+        #[test]
+        fn array_converts_to_itself() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"ary = [1, 2]; Array(ary).equal?(ary)").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn to_a_is_used_when_available() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"Array({ 1 => 2 }) == [[1, 2]]").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn to_a_returning_non_array_raises_type_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp
+                .eval(b"class Invalid; def to_a; 'nope'; end; end; Array(Invalid.new)")
+                .unwrap_err();
+            assert_eq!("TypeError", err.name().as_ref());
+            assert_eq!(
+                &b"can't convert Invalid to Array (Invalid#to_a gives String)"[..],
+                err.message().as_ref()
+            );
+        }
+
+        #[test]
+        fn plain_object_is_wrapped() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp
+                .eval(b"obj = BasicObject.new; Array(obj).length")
+                .unwrap();
+            assert_eq!(result.try_into::<Int>(&interp).unwrap(), 1);
+        }
+    }
+
+    mod at_exit {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn hooks_run_in_reverse_registration_order() {
+            let mut interp = crate::interpreter().unwrap();
+            let _ = interp
+                .eval(b"$order = []; at_exit { $order << :first }; at_exit { $order << :second }")
+                .unwrap();
+            interp.run_at_exit_hooks();
+            let result = interp.eval(b"$order == [:second, :first]").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            // Hooks are drained as they run, so a second pass is a no-op.
+            interp.run_at_exit_hooks();
+            let result = interp.eval(b"$order == [:second, :first]").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn raising_hook_does_not_abort_remaining_hooks() {
+            let mut interp = crate::interpreter().unwrap();
+            let _ = interp
+                .eval(b"$order = []; at_exit { $order << :survivor }; at_exit { raise 'boom' }")
+                .unwrap();
+            interp.run_at_exit_hooks();
+            let result = interp.eval(b"$order == [:survivor]").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn at_exit_without_a_block_is_an_argument_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp.eval(b"at_exit").unwrap_err();
+            assert_eq!("ArgumentError", err.name().as_ref());
+            assert_eq!(&b"called without a block"[..], err.message().as_ref());
+        }
+    }
+
+    mod caller {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn caller_reports_invoking_frames() {
+            let mut interp = crate::interpreter().unwrap();
+            let code = br#"
+def inner
+  caller
+end
+
+def outer
+  inner
+end
+outer
+"#;
+            let result = interp.eval(code).unwrap();
+            let frames = result.try_into_mut::<Vec<Vec<u8>>>(&mut interp).unwrap();
+            assert!(!frames.is_empty());
+            assert!(frames[0].ends_with(b":in outer"));
+        }
+
+        #[test]
+        fn caller_honors_start_and_length() {
+            let mut interp = crate::interpreter().unwrap();
+            let code = br#"
+def inner(start, length)
+  caller(start, length)
+end
+
+def outer(start, length)
+  inner(start, length)
+end
+[outer(2, nil).length < outer(1, nil).length, outer(1, 1).length, outer(1, 0)].inspect
+"#;
+            let result = interp.eval(code).unwrap();
+            let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+            assert_eq!("[true, 1, []]", result);
+        }
+
+        #[test]
+        fn caller_start_beyond_stack_depth_is_nil() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"caller(100)").unwrap();
+            assert!(result.is_nil());
+        }
+
+        #[test]
+        fn caller_rejects_negative_start() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp.eval(b"caller(-1)").unwrap_err();
+            assert_eq!("ArgumentError", err.name().as_ref());
+        }
+    }
+
+    mod catch_throw {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn throw_returns_value_to_matching_catch() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"catch(:done) { throw(:done, 42); 99 }").unwrap();
+            assert_eq!(result.try_into::<Int>(&interp).unwrap(), 42);
+        }
+
+        #[test]
+        fn catch_without_throw_returns_block_value() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"catch(:done) { 7 }").unwrap();
+            assert_eq!(result.try_into::<Int>(&interp).unwrap(), 7);
+        }
+
+        #[test]
+        fn generated_tag_is_caught_by_matching_throw() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"catch { |tag| throw(tag, 3) }").unwrap();
+            assert_eq!(result.try_into::<Int>(&interp).unwrap(), 3);
+        }
+
+        #[test]
+        fn non_matching_throw_propagates() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp
+                .eval(b"catch(:outer) { throw(:other, 1) }")
+                .unwrap_err();
+            assert_eq!("UncaughtThrowError", err.name().as_ref());
+            assert_eq!(&b"uncaught throw other"[..], err.message().as_ref());
+        }
+
+        #[test]
+        fn uncaught_throw_raises() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp.eval(b"throw(:loose)").unwrap_err();
+            assert_eq!("UncaughtThrowError", err.name().as_ref());
+        }
+    }
+
+    mod dir {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn dir_is_nil_when_evaluated_from_a_string() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"__dir__").unwrap();
+            assert!(result.is_nil());
+        }
+
+        #[test]
+        // TODO(GH-528): fix failing tests on Windows.
+        #[cfg_attr(target_os = "windows", should_panic)]
+        fn dir_tracks_the_innermost_required_file() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .def_rb_source_file(
+                    "outer.rb",
+                    &b"$outer_dir = __dir__; require_relative 'nested/inner'; $after_dir = __dir__"[..],
+                )
+                .unwrap();
+            interp
+                .def_rb_source_file("nested/inner.rb", &b"$inner_dir = __dir__"[..])
+                .unwrap();
+            let result = interp.eval(b"require 'outer'").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let result = interp
+                .eval(b"[$outer_dir, $inner_dir, $after_dir].inspect")
+                .unwrap();
+            let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+            assert_eq!(
+                r#"["/src/lib", "/src/lib/nested", "/src/lib"]"#,
+                result
+            );
+        }
+    }
+
+    mod float {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn parses_numeric_strings() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"Float('3.14')").unwrap();
+            let result = result.try_into::<Fp>(&interp).unwrap();
+            assert!((result - 3.14).abs() < Fp::EPSILON);
+        }
+
+        #[test]
+        fn ignores_whitespace_and_underscores() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"Float('  1_000.5  ')").unwrap();
+            let result = result.try_into::<Fp>(&interp).unwrap();
+            assert!((result - 1000.5).abs() < Fp::EPSILON);
+        }
+
+        #[test]
+        fn invalid_value_raises_argument_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp.eval(b"Float('abc')").unwrap_err();
+            assert_eq!("ArgumentError", err.name().as_ref());
+            assert_eq!(
+                &br#"invalid value for Float(): "abc""#[..],
+                err.message().as_ref()
+            );
+        }
+
+        #[test]
+        fn exception_false_returns_nil() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"Float('abc', exception: false)").unwrap();
+            assert!(result.is_nil());
+            let result = interp.eval(b"Float(nil, exception: false)").unwrap();
+            assert!(result.is_nil());
+        }
+
+        #[test]
+        fn nil_raises_type_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp.eval(b"Float(nil)").unwrap_err();
+            assert_eq!("TypeError", err.name().as_ref());
+        }
+    }
+
+    mod hash {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn nil_and_empty_array_convert_to_empty_hash() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"Hash(nil) == {}").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let result = interp.eval(b"Hash([]) == {}").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn hash_converts_to_itself() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp
+                .eval(b"hash = { 1 => 2 }; Hash(hash).equal?(hash)")
+                .unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn to_hash_is_used_when_available() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp
+                .eval(b"class Config; def to_hash; { a: 1 }; end; end; Hash(Config.new) == { a: 1 }")
+                .unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn non_empty_array_raises_type_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp.eval(b"Hash([[1, 2]])").unwrap_err();
+            assert_eq!("TypeError", err.name().as_ref());
+            assert_eq!(
+                &b"can't convert Array into Hash"[..],
+                err.message().as_ref()
+            );
+        }
+
+        #[test]
+        fn to_hash_returning_non_hash_raises_type_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp
+                .eval(b"class Invalid; def to_hash; 'nope'; end; end; Hash(Invalid.new)")
+                .unwrap_err();
+            assert_eq!("TypeError", err.name().as_ref());
+            assert_eq!(
+                &b"can't convert Invalid to Hash (Invalid#to_hash gives String)"[..],
+                err.message().as_ref()
+            );
+        }
+    }
+
+    mod sprintf {
+        use crate::test::prelude::*;
+
+        #[test]
+        fn zero_padded_integer() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"format('%05d', 42)").unwrap();
+            let result = result.try_into_mut::<String>(&mut interp).unwrap();
+            assert_eq!(result, "00042");
+        }
+
+        #[test]
+        fn left_aligned_string() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"sprintf('[%-5s]', 'ab')").unwrap();
+            let result = result.try_into_mut::<String>(&mut interp).unwrap();
+            assert_eq!(result, "[ab   ]");
+        }
+
+        #[test]
+        fn float_with_precision() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"format('%.2f', 3.14159)").unwrap();
+            let result = result.try_into_mut::<String>(&mut interp).unwrap();
+            assert_eq!(result, "3.14");
+        }
+
+        #[test]
+        fn general_float_selects_exponent_form() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"format('%.2g', 1234.5)").unwrap();
+            let result = result.try_into_mut::<String>(&mut interp).unwrap();
+            assert_eq!(result, "1.2e+03");
+        }
+
+        #[test]
+        fn general_float_strips_trailing_zeros_in_fixed_form() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"format('%g %g', 100.0, 0.0001)").unwrap();
+            let result = result.try_into_mut::<String>(&mut interp).unwrap();
+            assert_eq!(result, "100 0.0001");
+        }
+
+        #[test]
+        fn upper_general_float_uppercases_exponent() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"format('%G', 0.00001)").unwrap();
+            let result = result.try_into_mut::<String>(&mut interp).unwrap();
+            assert_eq!(result, "1E-05");
+        }
+
+        #[test]
+        fn alternate_general_float_keeps_trailing_zeros() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"format('%#g', 100.0)").unwrap();
+            let result = result.try_into_mut::<String>(&mut interp).unwrap();
+            assert_eq!(result, "100.000");
+        }
+
+        #[test]
+        fn hex_and_escaped_percent() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp.eval(b"sprintf('%x is 100%%', 255)").unwrap();
+            let result = result.try_into_mut::<String>(&mut interp).unwrap();
+            assert_eq!(result, "ff is 100%");
+        }
+
+        #[test]
+        fn named_reference_against_hash() {
+            let mut interp = crate::interpreter().unwrap();
+            let result = interp
+                .eval(b"format('%<animal>s says %<sound>s', animal: 'cow', sound: 'moo')")
+                .unwrap();
+            let result = result.try_into_mut::<String>(&mut interp).unwrap();
+            assert_eq!(result, "cow says moo");
+        }
+
+        #[test]
+        fn missing_named_reference_raises_key_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp.eval(b"format('%<missing>s', other: 1)").unwrap_err();
+            assert_eq!("KeyError", err.name().as_ref());
+            assert_eq!(&b"key<missing> not found"[..], err.message().as_ref());
+        }
+
+        #[test]
+        fn too_few_arguments_raises_argument_error() {
+            let mut interp = crate::interpreter().unwrap();
+            let err = interp.eval(b"format('%d %d', 1)").unwrap_err();
+            assert_eq!("ArgumentError", err.name().as_ref());
+            assert_eq!(&b"too few arguments"[..], err.message().as_ref());
+        }
+    }
+
+    mod sleep {
+        use std::time::Duration;
+
+        use crate::state::clock::Mock;
+        use crate::test::prelude::*;
+
+        #[test]
+        fn sleep_advances_mock_clock_without_blocking() {
+            let mut interp = crate::interpreter().unwrap();
+            interp.state.as_mut().unwrap().clock = Box::new(Mock::new());
+            let result = interp.eval(b"sleep(0.5)").unwrap();
+            let slept = result.try_into::<Int>(&interp).unwrap();
+            // `Kernel#sleep` returns the rounded number of seconds slept.
+            assert_eq!(slept, 1);
+            let elapsed = interp.state.as_ref().unwrap().clock.monotonic();
+            assert_eq!(elapsed, Duration::from_millis(500));
+        }
+
+        #[test]
+        fn sleep_integer_duration() {
+            let mut interp = crate::interpreter().unwrap();
+            interp.state.as_mut().unwrap().clock = Box::new(Mock::new());
+            let result = interp.eval(b"sleep(3)").unwrap();
+            let slept = result.try_into::<Int>(&interp).unwrap();
+            assert_eq!(slept, 3);
+            let elapsed = interp.state.as_ref().unwrap().clock.monotonic();
+            assert_eq!(elapsed, Duration::from_secs(3));
+        }
+
+        #[test]
+        fn negative_duration_raises_argument_error() {
+            let mut interp = crate::interpreter().unwrap();
+            interp.state.as_mut().unwrap().clock = Box::new(Mock::new());
+            let err = interp.eval(b"sleep(-1)").unwrap_err();
+            assert_eq!("ArgumentError", err.name().as_ref());
+            assert_eq!(
+                &b"time interval must not be negative"[..],
+                err.message().as_ref()
+            );
+        }
+    }
+
     mod require {
+        use crate::fs::MemoryResolver;
         use crate::test::prelude::*;
 
         #[derive(Debug)]
@@ -84,6 +548,36 @@ mod tests {
             assert_eq!(Some(expected), err.vm_backtrace(&mut interp),);
         }
 
+        #[test]
+        fn resolver_served_source_is_required_once() {
+            let mut interp = crate::interpreter().unwrap();
+            let mut resolver = MemoryResolver::new();
+            resolver.insert(
+                "/src/lib/from_memory.rb",
+                &b"$from_memory = ($from_memory || 0) + 1"[..],
+            );
+            interp.set_load_path_resolver(Box::new(resolver));
+            // The source is served entirely from the resolver; it was never
+            // defined on the virtual filesystem by the test.
+            let result = interp.eval(b"require 'from_memory'").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let result = interp.eval(b"$from_memory").unwrap();
+            assert_eq!(1, result.try_into::<Int>(&interp).unwrap());
+            // A second require of the same logical path is a no-op.
+            let result = interp.eval(b"require 'from_memory'").unwrap();
+            assert!(!result.try_into::<bool>(&interp).unwrap());
+            let result = interp.eval(b"$from_memory").unwrap();
+            assert_eq!(1, result.try_into::<Int>(&interp).unwrap());
+        }
+
+        #[test]
+        fn missing_resolver_path_falls_back_to_load_error() {
+            let mut interp = crate::interpreter().unwrap();
+            interp.set_load_path_resolver(Box::new(MemoryResolver::new()));
+            let err = interp.eval(b"require 'not-in-resolver'").unwrap_err();
+            assert_eq!("LoadError", err.name().as_ref());
+        }
+
         #[test]
         fn absolute_path() {
             let mut interp = crate::interpreter().unwrap();
@@ -162,5 +656,33 @@ mod tests {
                 "defined Ruby and Rust sources from single require"
             );
         }
+
+        #[test]
+        fn load_path_directories_are_searched() {
+            let mut interp = crate::interpreter().unwrap();
+            interp
+                .def_rb_source_file("/vendor/lib/feature.rb", &b"module Feature; end"[..])
+                .unwrap();
+            // `feature` is not reachable from the default load path.
+            let err = interp.eval(b"require 'feature'").unwrap_err();
+            assert_eq!("LoadError", err.name().as_ref());
+            interp.prepend_load_path(b"/vendor/lib").unwrap();
+            let result = interp.eval(b"require 'feature'").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+            let result = interp.eval(b"Feature.is_a?(Module)").unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
+
+        #[test]
+        fn load_path_entries_are_not_duplicated() {
+            let mut interp = crate::interpreter().unwrap();
+            interp.prepend_load_path(b"/vendor/lib").unwrap();
+            interp.prepend_load_path(b"/vendor/lib").unwrap();
+            interp.append_load_path(b"/vendor/lib").unwrap();
+            let result = interp
+                .eval(b"$LOAD_PATH == ['/vendor/lib', '/src/lib'] && $LOAD_PATH == $:")
+                .unwrap();
+            assert!(result.try_into::<bool>(&interp).unwrap());
+        }
     }
 }
@@ -8,6 +8,21 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     }
     let spec = module::Spec::new(interp, "Kernel", None)?;
     module::Builder::for_spec(interp, &spec)
+        .add_method("Array", artichoke_kernel_array, sys::mrb_args_req(1))?
+        .add_method("Hash", artichoke_kernel_hash, sys::mrb_args_req(1))?
+        .add_method("at_exit", artichoke_kernel_at_exit, sys::mrb_args_block())?
+        .add_method("caller", artichoke_kernel_caller, sys::mrb_args_opt(2))?
+        .add_method("__dir__", artichoke_kernel_dir, sys::mrb_args_none())?
+        .add_method(
+            "catch",
+            artichoke_kernel_catch,
+            sys::mrb_args_opt(1) | sys::mrb_args_block(),
+        )?
+        .add_method(
+            "throw",
+            artichoke_kernel_throw,
+            sys::mrb_args_req_and_opt(1, 1),
+        )?
         .add_method("require", artichoke_kernel_require, sys::mrb_args_rest())?
         .add_method(
             "require_relative",
@@ -15,9 +30,12 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
             sys::mrb_args_rest(),
         )?
         .add_method("load", artichoke_kernel_load, sys::mrb_args_rest())?
+        .add_method("format", artichoke_kernel_sprintf, sys::mrb_args_rest())?
         .add_method("p", artichoke_kernel_p, sys::mrb_args_rest())?
         .add_method("print", artichoke_kernel_print, sys::mrb_args_rest())?
         .add_method("puts", artichoke_kernel_puts, sys::mrb_args_rest())?
+        .add_method("sleep", artichoke_kernel_sleep, sys::mrb_args_req(1))?
+        .add_method("sprintf", artichoke_kernel_sprintf, sys::mrb_args_rest())?
         .define()?;
     interp.def_module::<kernel::Kernel>(spec)?;
     let _ = interp.eval(&include_bytes!("kernel.rb")[..])?;
@@ -33,6 +51,16 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .ok_or_else(|| NotDefinedError::module("Artichoke"))?;
     let spec = module::Spec::new(interp, "Kernel", Some(scope))?;
     module::Builder::for_spec(interp, &spec)
+        .add_method(
+            "Float",
+            artichoke_kernel_float,
+            sys::mrb_args_req_and_opt(1, 1),
+        )?
+        .add_self_method(
+            "Float",
+            artichoke_kernel_float,
+            sys::mrb_args_req_and_opt(1, 1),
+        )?
         .add_method(
             "Integer",
             artichoke_kernel_integer,
@@ -49,6 +77,127 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     Ok(())
 }
 
+unsafe extern "C" fn artichoke_kernel_array(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let arg = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let arg = Value::from(arg);
+    let result = trampoline::array(&mut guard, arg);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn artichoke_kernel_hash(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let arg = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let arg = Value::from(arg);
+    let result = trampoline::hash(&mut guard, arg);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn artichoke_kernel_at_exit(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let block = mrb_get_args!(mrb, &block);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let result = trampoline::at_exit(&mut guard, block);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn artichoke_kernel_caller(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (start, length) = mrb_get_args!(mrb, optional = 2);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let start = start.map(Value::from);
+    let length = length.map(Value::from);
+    let result = trampoline::caller(&mut guard, start, length);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn artichoke_kernel_catch(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (tag, block) = mrb_get_args!(mrb, optional = 1, &block);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let tag = tag.map(Value::from);
+    let result = trampoline::catch(&mut guard, tag, block);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn artichoke_kernel_throw(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (tag, value) = mrb_get_args!(mrb, required = 1, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let tag = Value::from(tag);
+    let value = value.map(Value::from);
+    let result = trampoline::throw(&mut guard, tag, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn artichoke_kernel_dir(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let result = trampoline::dir(&mut guard);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn artichoke_kernel_float(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (arg, exception) = mrb_get_args!(mrb, required = 1, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let arg = Value::from(arg);
+    let exception = exception.map(Value::from);
+    let result = trampoline::float(&mut guard, arg, exception);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn artichoke_kernel_integer(
     mrb: *mut sys::mrb_state,
     _slf: sys::mrb_value,
@@ -125,6 +274,36 @@ unsafe extern "C" fn artichoke_kernel_puts(
     }
 }
 
+unsafe extern "C" fn artichoke_kernel_sleep(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let duration = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let duration = Value::from(duration);
+    let result = trampoline::sleep(&mut guard, duration);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn artichoke_kernel_sprintf(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let args = args.iter().copied().map(Value::from);
+    let result = trampoline::sprintf(&mut guard, args);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn artichoke_kernel_require(
     mrb: *mut sys::mrb_state,
     _slf: sys::mrb_value,
@@ -10,6 +10,15 @@ use crate::state::parser::Context;
 
 const RUBY_EXTENSION: &str = "rb";
 
+// TODO: Honor `# frozen_string_literal: true` magic comments in required
+// files. The pragma is per compilation unit in MRI: a required file's own
+// pragma controls its literals regardless of the requiring file's pragma.
+// The vendored mruby parser has no magic comment support and its codegen
+// always emits mutable strings for `OP_STRING`, so implementing this
+// requires upstream parser and codegen changes. `load` and `require` already
+// isolate compilation units by pushing a fresh parser `Context` per file,
+// which is the boundary the pragma must attach to when support lands.
+
 pub fn load(interp: &mut Artichoke, filename: Value) -> Result<bool, Exception> {
     let filename = filename.implicitly_convert_to_string(interp)?;
     if filename.find_byte(b'\0').is_some() {
@@ -22,6 +31,7 @@ pub fn load(interp: &mut Artichoke, filename: Value) -> Result<bool, Exception>
         pathbuf = Path::new(RUBY_LOAD_PATH).join(file);
         path = pathbuf.as_path();
     }
+    resolve_external(interp, path)?;
     if !interp.source_is_file(path)? {
         let mut message = b"cannot load such file -- ".to_vec();
         message.extend_from_slice(filename);
@@ -47,35 +57,35 @@ pub fn require(
     let file = ffi::bytes_to_os_str(filename)?;
     let path = Path::new(file);
 
-    let (path, alternate) = if path.is_relative() {
-        let mut path = if let Some(ref base) = base {
-            base.join(path)
+    let mut candidates = Vec::new();
+    if path.is_relative() {
+        let resolved = if let Some(ref base) = base {
+            vec![base.join(path)]
         } else {
-            Path::new(RUBY_LOAD_PATH).join(path)
+            load_paths(interp)?
+                .iter()
+                .map(|dir| dir.join(path))
+                .collect()
         };
-        let is_rb = path
-            .extension()
-            .filter(|ext| ext == &RUBY_EXTENSION)
-            .is_some();
-        if is_rb {
-            (path, None)
-        } else {
-            let alternate = path.clone();
-            path.set_extension(RUBY_EXTENSION);
-            (path, Some(alternate))
+        for mut path in resolved {
+            let is_rb = path
+                .extension()
+                .filter(|ext| ext == &RUBY_EXTENSION)
+                .is_some();
+            if is_rb {
+                candidates.push(path);
+            } else {
+                let alternate = path.clone();
+                path.set_extension(RUBY_EXTENSION);
+                candidates.push(path);
+                candidates.push(alternate);
+            }
         }
     } else {
-        (path.to_owned(), None)
-    };
-    if interp.source_is_file(&path)? {
-        let context = Context::new(ffi::os_str_to_bytes(path.as_os_str())?.to_vec())
-            .ok_or_else(|| ArgumentError::from("path name contains null byte"))?;
-        interp.push_context(context)?;
-        let result = interp.require_source(&path);
-        let _ = interp.pop_context()?;
-        return result;
-    }
-    if let Some(path) = alternate {
+        candidates.push(path.to_owned());
+    }
+    for path in candidates {
+        resolve_external(interp, &path)?;
         if interp.source_is_file(&path)? {
             let context = Context::new(ffi::os_str_to_bytes(path.as_os_str())?.to_vec())
                 .ok_or_else(|| ArgumentError::from("path name contains null byte"))?;
@@ -90,6 +100,54 @@ pub fn require(
     Err(LoadError::from(message).into())
 }
 
+/// Consult the embedder-installed
+/// [`LoadPathResolver`](crate::fs::LoadPathResolver) for `path`.
+///
+/// On a hit, the resolved source is defined into the virtual filesystem at
+/// `path` so the regular `require` machinery -- including require-once
+/// tracking -- applies to it. Sources already present in the virtual
+/// filesystem are not replaced.
+fn resolve_external(interp: &mut Artichoke, path: &Path) -> Result<(), Exception> {
+    let name = if let Some(name) = path.to_str() {
+        name
+    } else {
+        return Ok(());
+    };
+    let contents = {
+        let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+        if let Some(ref resolver) = state.resolver {
+            resolver.resolve(name)
+        } else {
+            return Ok(());
+        }
+    };
+    if let Some(contents) = contents {
+        if !interp.source_is_file(path)? {
+            interp.def_rb_source_file(path, contents)?;
+        }
+    }
+    Ok(())
+}
+
+/// Directories that `require` searches for relative paths.
+///
+/// The load path is stored in the `$LOAD_PATH` global. Interpreters that have
+/// never modified `$LOAD_PATH` fall back to [`RUBY_LOAD_PATH`].
+fn load_paths(interp: &mut Artichoke) -> Result<Vec<PathBuf>, Exception> {
+    let mut dirs = Vec::new();
+    if let Some(load_path) = interp.get_global_variable(&b"$LOAD_PATH"[..])? {
+        let entries: Vec<Vec<u8>> = interp.try_convert_mut(load_path).unwrap_or_default();
+        for entry in &entries {
+            let dir = ffi::bytes_to_os_str(entry)?;
+            dirs.push(PathBuf::from(dir));
+        }
+    }
+    if dirs.is_empty() {
+        dirs.push(PathBuf::from(RUBY_LOAD_PATH));
+    }
+    Ok(dirs)
+}
+
 #[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct RelativePath(PathBuf);
 
@@ -0,0 +1,375 @@
+//! Format string engine for `Kernel#sprintf` and `Kernel#format`.
+//!
+//! The engine implements the commonly used subset of Ruby's format
+//! specifications: integer (`%b`, `%o`, `%d`, `%i`, `%u`, `%x`, `%X`), float
+//! (`%e`, `%E`, `%f`, `%g`, `%G`), and string (`%s`) conversions with flags
+//! (`-`, `0`, `+`, space, `#`), width, precision, escaped percents (`%%`), and
+//! named references (`%<name>s`, `%{name}`) resolved against a trailing hash
+//! argument.
+
+use std::convert::TryFrom;
+
+use crate::extn::core::symbol::Symbol;
+use crate::extn::prelude::*;
+
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct Flags {
+    left_align: bool,
+    zero_pad: bool,
+    sign_plus: bool,
+    sign_space: bool,
+    alternate: bool,
+}
+
+#[derive(Debug)]
+struct Arguments<'a> {
+    args: &'a [Value],
+    next: usize,
+}
+
+impl<'a> Arguments<'a> {
+    fn next_positional(&mut self) -> Result<Value, Exception> {
+        let arg = self
+            .args
+            .get(self.next)
+            .copied()
+            .ok_or_else(|| ArgumentError::from("too few arguments"))?;
+        self.next += 1;
+        Ok(arg)
+    }
+
+    fn named(&self, interp: &mut Artichoke, name: &[u8]) -> Result<Value, Exception> {
+        let hash = self
+            .args
+            .first()
+            .copied()
+            .ok_or_else(|| ArgumentError::from("one hash required"))?;
+        if !matches!(hash.ruby_type(), Ruby::Hash) {
+            return Err(ArgumentError::from("one hash required").into());
+        }
+        let sym = interp.intern_bytes(name.to_vec())?;
+        let key = Symbol::alloc_value(sym.into(), interp)?;
+        let contains = hash.funcall(interp, "key?", &[key], None)?;
+        if !contains.try_into::<bool>(interp)? {
+            let mut message = String::from("key<");
+            string::format_unicode_debug_into(&mut message, name)?;
+            message.push_str("> not found");
+            return Err(KeyError::from(message).into());
+        }
+        hash.funcall(interp, "[]", &[key], None)
+    }
+}
+
+fn format_integer(
+    interp: &mut Artichoke,
+    arg: Value,
+    radix: u32,
+    upper: bool,
+    flags: Flags,
+) -> Result<Vec<u8>, Exception> {
+    let int = arg.implicitly_convert_to_int(interp)?;
+    // Widen through `i128` so `Int::min_value()` has a representable
+    // magnitude.
+    let magnitude = i128::from(int).checked_abs().unwrap_or_default();
+    let digits = match radix {
+        2 => format!("{:b}", magnitude),
+        8 => format!("{:o}", magnitude),
+        16 if upper => format!("{:X}", magnitude),
+        16 => format!("{:x}", magnitude),
+        _ => format!("{}", magnitude),
+    };
+    let mut formatted = String::with_capacity(digits.len() + 3);
+    if int < 0 {
+        formatted.push('-');
+    } else if flags.sign_plus {
+        formatted.push('+');
+    } else if flags.sign_space {
+        formatted.push(' ');
+    }
+    if flags.alternate {
+        match radix {
+            2 => formatted.push_str("0b"),
+            8 => formatted.push('0'),
+            16 if upper => formatted.push_str("0X"),
+            16 => formatted.push_str("0x"),
+            _ => {}
+        }
+    }
+    formatted.push_str(digits.as_str());
+    Ok(formatted.into_bytes())
+}
+
+fn format_float(
+    interp: &mut Artichoke,
+    arg: Value,
+    conversion: u8,
+    flags: Flags,
+    precision: Option<usize>,
+) -> Result<Vec<u8>, Exception> {
+    let float = match arg.ruby_type() {
+        Ruby::Float => arg.try_into::<Fp>(interp)?,
+        _ => {
+            let int = arg.implicitly_convert_to_int(interp)?;
+            #[allow(clippy::cast_precision_loss)]
+            {
+                int as Fp
+            }
+        }
+    };
+    let magnitude = float.abs();
+    let rendered = match conversion {
+        b'e' | b'E' => {
+            let precision = precision.unwrap_or(6);
+            let formatted = format!("{:.*e}", precision, magnitude);
+            // Rust renders `1.500000e2`; Ruby renders `1.500000e+02`.
+            if let Some(pos) = formatted.find('e') {
+                let mantissa = &formatted[..pos];
+                let mut exponent = &formatted[pos + 1..];
+                let mut rendered = String::from(mantissa);
+                rendered.push(if conversion == b'E' { 'E' } else { 'e' });
+                if exponent.starts_with('-') {
+                    rendered.push('-');
+                    exponent = &exponent[1..];
+                } else {
+                    rendered.push('+');
+                }
+                if exponent.len() < 2 {
+                    rendered.push('0');
+                }
+                rendered.push_str(exponent);
+                rendered
+            } else {
+                formatted
+            }
+        }
+        b'g' | b'G' => {
+            // `%g` precision counts significant digits rather than fractional
+            // digits. A precision of zero is treated as one.
+            let precision = match precision.unwrap_or(6) {
+                0 => 1,
+                precision => precision,
+            };
+            // Rendering with `%e` first determines the decimal exponent after
+            // rounding to the requested significant digits, e.g. `%.1g` turns
+            // `0.99` into `1e+00` rather than `9.9e-01`.
+            let sci = format!("{:.*e}", precision - 1, magnitude);
+            if let Some(pos) = sci.find('e') {
+                let exponent = sci[pos + 1..].parse::<i64>().unwrap_or_default();
+                let threshold = i64::try_from(precision).unwrap_or_else(|_| i64::max_value());
+                if exponent < -4 || exponent >= threshold {
+                    let mut rendered = String::from(&sci[..pos]);
+                    if !flags.alternate && rendered.contains('.') {
+                        let digits = rendered.trim_end_matches('0').trim_end_matches('.').len();
+                        rendered.truncate(digits);
+                    }
+                    rendered.push(if conversion == b'G' { 'E' } else { 'e' });
+                    rendered.push(if exponent < 0 { '-' } else { '+' });
+                    let exponent = exponent.checked_abs().unwrap_or_default();
+                    if exponent < 10 {
+                        rendered.push('0');
+                    }
+                    rendered.push_str(exponent.to_string().as_str());
+                    rendered
+                } else {
+                    let fractional = usize::try_from(threshold - 1 - exponent).unwrap_or_default();
+                    let mut rendered = format!("{:.*}", fractional, magnitude);
+                    if !flags.alternate && rendered.contains('.') {
+                        let digits = rendered.trim_end_matches('0').trim_end_matches('.').len();
+                        rendered.truncate(digits);
+                    }
+                    rendered
+                }
+            } else {
+                sci
+            }
+        }
+        _ => {
+            let precision = precision.unwrap_or(6);
+            format!("{:.*}", precision, magnitude)
+        }
+    };
+    let mut formatted = String::with_capacity(rendered.len() + 1);
+    if float.is_sign_negative() {
+        formatted.push('-');
+    } else if flags.sign_plus {
+        formatted.push('+');
+    } else if flags.sign_space {
+        formatted.push(' ');
+    }
+    formatted.push_str(rendered.as_str());
+    Ok(formatted.into_bytes())
+}
+
+fn pad_into(
+    out: &mut Vec<u8>,
+    formatted: &[u8],
+    width: Option<usize>,
+    flags: Flags,
+    numeric: bool,
+) {
+    let width = width.unwrap_or(0);
+    if formatted.len() >= width {
+        out.extend_from_slice(formatted);
+        return;
+    }
+    let padding = width - formatted.len();
+    if flags.left_align {
+        out.extend_from_slice(formatted);
+        out.resize(out.len() + padding, b' ');
+    } else if flags.zero_pad && numeric {
+        // Zero padding goes between the sign and the digits.
+        let sign = formatted
+            .iter()
+            .take_while(|&&byte| byte == b'-' || byte == b'+' || byte == b' ')
+            .count();
+        out.extend_from_slice(&formatted[..sign]);
+        out.resize(out.len() + padding, b'0');
+        out.extend_from_slice(&formatted[sign..]);
+    } else {
+        out.resize(out.len() + padding, b' ');
+        out.extend_from_slice(formatted);
+    }
+}
+
+/// Render a format specification with the given arguments.
+///
+/// # Errors
+///
+/// If the specification consumes more positional arguments than were given,
+/// an `ArgumentError` is returned. If a named reference is missing from the
+/// hash argument, a `KeyError` is returned. Malformed specifications and
+/// unconvertible arguments also return errors.
+pub fn format(interp: &mut Artichoke, spec: &[u8], args: &[Value]) -> Result<Vec<u8>, Exception> {
+    let mut arguments = Arguments { args, next: 0 };
+    let mut out = Vec::with_capacity(spec.len());
+    let mut idx = 0;
+    while idx < spec.len() {
+        let byte = spec[idx];
+        idx += 1;
+        if byte != b'%' {
+            out.push(byte);
+            continue;
+        }
+
+        // Named references come immediately after the `%`.
+        let mut value = None;
+        if let Some(&open) = spec.get(idx) {
+            if open == b'<' || open == b'{' {
+                let close = if open == b'<' { b'>' } else { b'}' };
+                idx += 1;
+                let start = idx;
+                let end = loop {
+                    match spec.get(idx) {
+                        Some(&byte) if byte == close => break idx,
+                        Some(_) => idx += 1,
+                        None => {
+                            return Err(ArgumentError::from("malformed format string").into());
+                        }
+                    }
+                };
+                let name = &spec[start..end];
+                idx = end + 1;
+                let referenced = arguments.named(interp, name)?;
+                if open == b'{' {
+                    // `%{name}` interpolates the value with no conversion.
+                    let display = referenced.to_s(interp);
+                    out.extend_from_slice(&display);
+                    continue;
+                }
+                value = Some(referenced);
+            }
+        }
+
+        let mut flags = Flags::default();
+        loop {
+            match spec.get(idx) {
+                Some(b'-') => flags.left_align = true,
+                Some(b'0') => flags.zero_pad = true,
+                Some(b'+') => flags.sign_plus = true,
+                Some(b' ') => flags.sign_space = true,
+                Some(b'#') => flags.alternate = true,
+                _ => break,
+            }
+            idx += 1;
+        }
+
+        let mut width = None::<usize>;
+        while let Some(&byte) = spec.get(idx) {
+            if byte.is_ascii_digit() {
+                width = Some(width.unwrap_or(0) * 10 + usize::from(byte - b'0'));
+                idx += 1;
+            } else {
+                break;
+            }
+        }
+
+        let mut precision = None::<usize>;
+        if let Some(b'.') = spec.get(idx) {
+            idx += 1;
+            let mut digits = 0;
+            while let Some(&byte) = spec.get(idx) {
+                if byte.is_ascii_digit() {
+                    digits = digits * 10 + usize::from(byte - b'0');
+                    idx += 1;
+                } else {
+                    break;
+                }
+            }
+            precision = Some(digits);
+        }
+
+        let conversion = spec.get(idx).copied().ok_or_else(|| {
+            ArgumentError::from("incomplete format specifier; use %% (double %) instead")
+        })?;
+        idx += 1;
+
+        if conversion == b'%' {
+            out.push(b'%');
+            continue;
+        }
+        let arg = if let Some(value) = value {
+            value
+        } else {
+            arguments.next_positional()?
+        };
+        match conversion {
+            b'b' => {
+                let formatted = format_integer(interp, arg, 2, false, flags)?;
+                pad_into(&mut out, &formatted, width, flags, true);
+            }
+            b'o' => {
+                let formatted = format_integer(interp, arg, 8, false, flags)?;
+                pad_into(&mut out, &formatted, width, flags, true);
+            }
+            b'd' | b'i' | b'u' => {
+                let formatted = format_integer(interp, arg, 10, false, flags)?;
+                pad_into(&mut out, &formatted, width, flags, true);
+            }
+            b'x' => {
+                let formatted = format_integer(interp, arg, 16, false, flags)?;
+                pad_into(&mut out, &formatted, width, flags, true);
+            }
+            b'X' => {
+                let formatted = format_integer(interp, arg, 16, true, flags)?;
+                pad_into(&mut out, &formatted, width, flags, true);
+            }
+            b'e' | b'E' | b'f' | b'g' | b'G' => {
+                let formatted = format_float(interp, arg, conversion, flags, precision)?;
+                pad_into(&mut out, &formatted, width, flags, true);
+            }
+            b's' => {
+                let mut formatted = arg.to_s(interp);
+                if let Some(precision) = precision {
+                    formatted.truncate(precision);
+                }
+                pad_into(&mut out, &formatted, width, flags, false);
+            }
+            conversion => {
+                let mut message = String::from("malformed format string - %");
+                message.push(char::from(conversion));
+                return Err(ArgumentError::from(message).into());
+            }
+        }
+    }
+    Ok(out)
+}
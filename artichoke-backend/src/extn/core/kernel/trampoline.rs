@@ -1,6 +1,143 @@
+use std::convert::TryFrom;
+use std::path::Path;
+use std::time::Duration;
+
 use crate::extn::core::kernel;
 use crate::extn::core::kernel::require::RelativePath;
 use crate::extn::prelude::*;
+use crate::ffi;
+use crate::state::parser::TOP_FILENAME;
+
+pub fn array(interp: &mut Artichoke, arg: Value) -> Result<Value, Exception> {
+    kernel::array::method(interp, arg)
+}
+
+pub fn hash(interp: &mut Artichoke, arg: Value) -> Result<Value, Exception> {
+    kernel::hash::method(interp, arg)
+}
+
+pub fn at_exit(interp: &mut Artichoke, block: Option<Block>) -> Result<Value, Exception> {
+    let block = if let Some(block) = block {
+        block
+    } else {
+        return Err(ArgumentError::from("called without a block").into());
+    };
+    let proc = block.inner();
+    // Root the proc so the hook survives garbage collections between
+    // registration and interpreter close.
+    unsafe {
+        interp.with_ffi_boundary(|mrb| sys::mrb_gc_register(mrb, proc))?;
+    }
+    let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+    state.at_exit.push(proc);
+    Ok(Value::from(proc))
+}
+
+pub fn caller(
+    interp: &mut Artichoke,
+    start: Option<Value>,
+    length: Option<Value>,
+) -> Result<Value, Exception> {
+    let start = if let Some(start) = start.filter(|start| !start.is_nil()) {
+        let start = start.implicitly_convert_to_int(interp)?;
+        usize::try_from(start)
+            .map_err(|_| ArgumentError::from(format!("negative level ({})", start)))?
+    } else {
+        // The default start of 1 excludes the frame that invoked `caller`.
+        1
+    };
+    let length = if let Some(length) = length.filter(|length| !length.is_nil()) {
+        let length = length.implicitly_convert_to_int(interp)?;
+        let length = usize::try_from(length)
+            .map_err(|_| ArgumentError::from(format!("negative size ({})", length)))?;
+        Some(length)
+    } else {
+        None
+    };
+    // `mrb_get_backtrace` unpacks the current call stack innermost first and
+    // skips C function frames, so the `Kernel#caller` shim itself is never
+    // reported and index 0 is the invocation site of `caller`.
+    let backtrace = unsafe { interp.with_ffi_boundary(|mrb| sys::mrb_get_backtrace(mrb))? };
+    let frames = Value::from(backtrace).try_into_mut::<Vec<Value>>(interp)?;
+    if start > frames.len() {
+        return Ok(Value::nil());
+    }
+    let frames = frames.into_iter().skip(start);
+    let frames = if let Some(length) = length {
+        frames.take(length).collect::<Vec<_>>()
+    } else {
+        frames.collect::<Vec<_>>()
+    };
+    interp.try_convert_mut(frames)
+}
+
+pub fn dir(interp: &mut Artichoke) -> Result<Value, Exception> {
+    let filename = if let Some(context) = interp.peek_context()? {
+        context.filename().to_vec()
+    } else {
+        return Ok(Value::nil());
+    };
+    if filename == TOP_FILENAME {
+        // Code evaluated from a string has no backing file.
+        return Ok(Value::nil());
+    }
+    // `require` and `load` push a fresh parser context per file, so the top of
+    // the context stack is always the innermost file being executed.
+    let path = ffi::bytes_to_os_str(&filename)?;
+    let base = Path::new(path).parent().unwrap_or_else(|| Path::new("/"));
+    let dir = ffi::os_str_to_bytes(base.as_os_str())?.to_vec();
+    Ok(interp.convert_mut(dir))
+}
+
+pub fn float(
+    interp: &mut Artichoke,
+    arg: Value,
+    exception: Option<Value>,
+) -> Result<Value, Exception> {
+    fn convert(interp: &mut Artichoke, arg: Value) -> Result<Value, Exception> {
+        match arg.ruby_type() {
+            Ruby::Float => Ok(arg),
+            Ruby::Fixnum => {
+                let int = arg.try_into::<Int>(interp)?;
+                #[allow(clippy::cast_precision_loss)]
+                Ok(interp.convert_mut(int as Fp))
+            }
+            Ruby::Nil => Err(TypeError::from("can't convert nil into Float").into()),
+            Ruby::String => {
+                let string = arg.implicitly_convert_to_string(interp)?;
+                let float = kernel::float::method(string)?;
+                Ok(interp.convert_mut(float))
+            }
+            _ => {
+                if let Ok(true) = arg.respond_to(interp, "to_f") {
+                    let float = arg.funcall(interp, "to_f", &[], None)?;
+                    if let Ruby::Float = float.ruby_type() {
+                        return Ok(float);
+                    }
+                }
+                let mut message = String::from("can't convert ");
+                message.push_str(arg.pretty_name(interp));
+                message.push_str(" into Float");
+                Err(TypeError::from(message).into())
+            }
+        }
+    }
+
+    // A `false` or `nil` exception flag turns conversion failures into `nil`.
+    let raise = if let Some(exception) = exception {
+        exception
+            .try_into::<Option<bool>>(interp)
+            .unwrap_or(Some(true))
+            .unwrap_or_default()
+    } else {
+        true
+    };
+    match convert(interp, arg) {
+        Ok(value) => Ok(value),
+        Err(_) if !raise => Ok(Value::nil()),
+        Err(exception) => Err(exception),
+    }
+}
 
 pub fn integer(
     interp: &mut Artichoke,
@@ -84,6 +221,36 @@ where
     }
 }
 
+pub fn sleep(interp: &mut Artichoke, duration: Value) -> Result<Value, Exception> {
+    let duration = if let Ruby::Float = duration.ruby_type() {
+        let seconds = duration.try_into::<Fp>(interp)?;
+        if seconds < 0.0 {
+            return Err(ArgumentError::from("time interval must not be negative").into());
+        }
+        Duration::from_secs_f64(seconds)
+    } else {
+        let seconds = duration.implicitly_convert_to_int(interp)?;
+        if seconds < 0 {
+            return Err(ArgumentError::from("time interval must not be negative").into());
+        }
+        #[allow(clippy::cast_sign_loss)]
+        Duration::from_secs(seconds as u64)
+    };
+    let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+    let start = state.clock.monotonic();
+    state.clock.sleep(duration);
+    let slept = state
+        .clock
+        .monotonic()
+        .checked_sub(start)
+        .unwrap_or_default();
+    // `Kernel#sleep` returns the number of seconds slept, rounded to the
+    // nearest whole second.
+    #[allow(clippy::cast_possible_truncation)]
+    let slept = slept.as_secs_f64().round() as Int;
+    Ok(interp.convert(slept))
+}
+
 pub fn require(interp: &mut Artichoke, path: Value) -> Result<Value, Exception> {
     let success = kernel::require::require(interp, path, None)?;
     Ok(interp.convert(success))
@@ -94,3 +261,31 @@ pub fn require_relative(interp: &mut Artichoke, path: Value) -> Result<Value, Ex
     let success = kernel::require::require(interp, path, Some(relative_base))?;
     Ok(interp.convert(success))
 }
+
+pub fn sprintf<T>(interp: &mut Artichoke, args: T) -> Result<Value, Exception>
+where
+    T: IntoIterator<Item = Value>,
+{
+    let mut args = args.into_iter();
+    let spec = args
+        .next()
+        .ok_or_else(|| ArgumentError::from("too few arguments"))?;
+    // Copy the format string out of the mruby heap so a garbage collection
+    // triggered while formatting cannot invalidate it.
+    let spec = spec.implicitly_convert_to_string(interp)?.to_vec();
+    let args = args.collect::<Vec<_>>();
+    let formatted = kernel::sprintf::format(interp, &spec, &args)?;
+    Ok(interp.convert_mut(formatted))
+}
+
+pub fn catch(
+    interp: &mut Artichoke,
+    tag: Option<Value>,
+    block: Option<Block>,
+) -> Result<Value, Exception> {
+    kernel::catch_throw::catch(interp, tag, block)
+}
+
+pub fn throw(interp: &mut Artichoke, tag: Value, value: Option<Value>) -> Result<Value, Exception> {
+    kernel::catch_throw::throw(interp, tag, value)
+}
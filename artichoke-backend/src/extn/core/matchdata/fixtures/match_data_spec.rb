@@ -0,0 +1,15 @@
+# frozen_string_literal: true
+
+match = 'hello world'.match(/(?<greeting>hello) (?<subject>\w+)/)
+
+raise 'expected a MatchData' if match.nil?
+raise unless match[0] == 'hello world'
+raise unless match[1] == 'hello'
+raise unless match[:greeting] == 'hello'
+raise unless match['subject'] == 'world'
+raise unless match.pre_match == ''
+raise unless match.post_match == ''
+raise unless match.captures == %w[hello world]
+raise unless match.names == %w[greeting subject]
+
+raise unless 'artichoke'.match(/xyzzy/).nil?
@@ -176,6 +176,12 @@ impl MatchData {
 
     #[inline]
     pub fn begin(&self, capture: Capture<'_>) -> Result<Option<usize>, Exception> {
+        // Group 0 always exists, so the whole-match offset can be computed
+        // without resolving the capture against the group count.
+        if let Capture::GroupIndex(0) = capture {
+            let begin = self.match_begin()?.map(|begin| self.char_offset(begin));
+            return Ok(begin);
+        }
         if let Some([begin, _]) = self.offset(capture)? {
             Ok(Some(begin))
         } else {
@@ -183,6 +189,57 @@ impl MatchData {
         }
     }
 
+    /// Return the byte offset into the haystack where the whole match begins.
+    ///
+    /// Unlike [`begin`](Self::begin), the offset is always a byte count, even
+    /// when the haystack is valid UTF-8. Returns `None` if the pattern did
+    /// not match.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying regexp backend fails to compute match positions, an
+    /// error is returned.
+    pub fn match_begin(&self) -> Result<Option<usize>, Exception> {
+        let haystack = self.matched_region();
+        if let Some((begin, _)) = self.regexp.inner().pos(haystack, 0)? {
+            Ok(Some(self.region.offset() + begin))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Return the byte offset into the haystack just past the whole match.
+    ///
+    /// Unlike [`end`](Self::end), the offset is always a byte count, even
+    /// when the haystack is valid UTF-8. Returns `None` if the pattern did
+    /// not match.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying regexp backend fails to compute match positions, an
+    /// error is returned.
+    pub fn match_end(&self) -> Result<Option<usize>, Exception> {
+        let haystack = self.matched_region();
+        if let Some((_, end)) = self.regexp.inner().pos(haystack, 0)? {
+            Ok(Some(self.region.offset() + end))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Convert a haystack byte offset to the offset [`offset`](Self::offset)
+    /// reports: a character count for UTF-8 haystacks, the byte position
+    /// otherwise.
+    fn char_offset(&self, byte_offset: usize) -> usize {
+        let haystack = self.matched_region();
+        let pos = byte_offset - self.region.offset();
+        if let Some(Ok(prefix)) = haystack.get(..pos).map(str::from_utf8) {
+            self.region.offset() + prefix.chars().count()
+        } else {
+            self.region.offset() + haystack.len()
+        }
+    }
+
     pub fn capture_at(&self, at: CaptureAt<'_>) -> Result<CaptureMatch, Exception> {
         let haystack = self.matched_region();
         let captures = if let Some(captures) = self.regexp.inner().captures(haystack)? {
@@ -273,8 +330,69 @@ impl MatchData {
         }
     }
 
+    /// Return an iterator over capture groups yielding borrowed slices of the
+    /// haystack.
+    ///
+    /// Like [`captures`](Self::captures), the iterator excludes the implicit
+    /// zero group for the whole match. Groups that did not participate in the
+    /// match yield `None`.
+    ///
+    /// This streaming API lets embedders walk captures without allocating an
+    /// intermediate `Array`.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying regexp backend fails to compute the number of capture
+    /// groups, an error is returned.
+    pub fn captures_iter(&self) -> Result<CapturesIter<'_>, Exception> {
+        let haystack = self.matched_region();
+        let len = self.regexp.inner().captures_len(Some(haystack))?;
+        Ok(CapturesIter {
+            data: self,
+            haystack,
+            // Skip the implicit zero group for the whole match.
+            idx: 1,
+            len,
+        })
+    }
+
+    /// Return an iterator over named capture groups yielding group names and
+    /// borrowed slices of the haystack.
+    ///
+    /// Groups that did not participate in the match yield `None`. Like
+    /// [`named_captures`](Self::named_captures), a name that is bound to
+    /// multiple groups yields the last participating capture.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying regexp backend fails to resolve group names, an error
+    /// is returned.
+    pub fn named_captures_iter(&self) -> Result<NamedCapturesIter<'_>, Exception> {
+        let haystack = self.matched_region();
+        let mut names = Vec::new();
+        for name in self.regexp.names() {
+            let indexes = self
+                .regexp
+                .inner()
+                .capture_indexes_for_name(&name)?
+                .unwrap_or_default();
+            names.push((name, indexes));
+        }
+        Ok(NamedCapturesIter {
+            data: self,
+            haystack,
+            names: names.into_iter(),
+        })
+    }
+
     #[inline]
     pub fn end(&self, capture: Capture<'_>) -> Result<Option<usize>, Exception> {
+        // Group 0 always exists, so the whole-match offset can be computed
+        // without resolving the capture against the group count.
+        if let Capture::GroupIndex(0) = capture {
+            let end = self.match_end()?.map(|end| self.char_offset(end));
+            return Ok(end);
+        }
         if let Some([_, end]) = self.offset(capture)? {
             Ok(Some(end))
         } else {
@@ -402,3 +520,189 @@ impl MatchData {
         self.regexp.inner().capture0(haystack)
     }
 }
+
+/// An iterator over the capture groups in a [`MatchData`].
+///
+/// This struct is created by [`MatchData::captures_iter`].
+#[derive(Debug)]
+pub struct CapturesIter<'a> {
+    data: &'a MatchData,
+    haystack: &'a [u8],
+    idx: usize,
+    len: usize,
+}
+
+impl<'a> Iterator for CapturesIter<'a> {
+    type Item = Option<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.len {
+            return None;
+        }
+        let pos = self.data.regexp.inner().pos(self.haystack, self.idx);
+        self.idx += 1;
+        if let Ok(Some((begin, end))) = pos {
+            Some(self.haystack.get(begin..end))
+        } else {
+            Some(None)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len.saturating_sub(self.idx);
+        (remaining, Some(remaining))
+    }
+}
+
+/// An iterator over the named capture groups in a [`MatchData`].
+///
+/// This struct is created by [`MatchData::named_captures_iter`].
+#[derive(Debug)]
+pub struct NamedCapturesIter<'a> {
+    data: &'a MatchData,
+    haystack: &'a [u8],
+    names: std::vec::IntoIter<(Vec<u8>, Vec<usize>)>,
+}
+
+impl<'a> Iterator for NamedCapturesIter<'a> {
+    type Item = (Vec<u8>, Option<&'a [u8]>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (name, indexes) = self.names.next()?;
+        let mut capture = None;
+        for index in indexes {
+            if let Ok(Some((begin, end))) = self.data.regexp.inner().pos(self.haystack, index) {
+                if let Some(matched) = self.haystack.get(begin..end) {
+                    capture = Some(matched);
+                }
+            }
+        }
+        Some((name, capture))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.names.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MatchData;
+    use crate::test::prelude::*;
+
+    #[test]
+    fn ruby_spec_fixtures() {
+        let mut interp = crate::interpreter().unwrap();
+        let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src")
+            .join("extn")
+            .join("core")
+            .join("matchdata")
+            .join("fixtures");
+        crate::test::run_specs(&mut interp, fixtures);
+    }
+
+    #[test]
+    fn captures_iter_yields_all_groups() {
+        let mut interp = crate::interpreter().unwrap();
+        let mut value = interp
+            .eval(b"/(?<a>\\d+) (?<b>\\w+)/.match('10 artichokes')")
+            .unwrap();
+        let data = unsafe { MatchData::unbox_from_value(&mut value, &mut interp).unwrap() };
+        let captures = data.captures_iter().unwrap().collect::<Vec<_>>();
+        assert_eq!(captures, vec![Some(&b"10"[..]), Some(&b"artichokes"[..])]);
+    }
+
+    #[test]
+    fn captures_iter_yields_none_for_non_participating_groups() {
+        let mut interp = crate::interpreter().unwrap();
+        let mut value = interp.eval(b"/(\\d+)(x)?/.match('10')").unwrap();
+        let data = unsafe { MatchData::unbox_from_value(&mut value, &mut interp).unwrap() };
+        let captures = data.captures_iter().unwrap().collect::<Vec<_>>();
+        assert_eq!(captures, vec![Some(&b"10"[..]), None]);
+    }
+
+    #[test]
+    fn named_captures_iter_yields_names_and_captures() {
+        let mut interp = crate::interpreter().unwrap();
+        let mut value = interp.eval(b"/(?<a>\\d+)(?<b>x)?/.match('10')").unwrap();
+        let data = unsafe { MatchData::unbox_from_value(&mut value, &mut interp).unwrap() };
+        let captures = data.named_captures_iter().unwrap().collect::<Vec<_>>();
+        assert_eq!(
+            captures,
+            vec![(b"a".to_vec(), Some(&b"10"[..])), (b"b".to_vec(), None)]
+        );
+    }
+
+    #[test]
+    fn match_begin_and_end_are_byte_offsets() {
+        let mut interp = crate::interpreter().unwrap();
+        let mut value = interp
+            .eval("/w\u{f6}rld/.match('h\u{e9}llo w\u{f6}rld')".as_bytes())
+            .unwrap();
+        let data = unsafe { MatchData::unbox_from_value(&mut value, &mut interp).unwrap() };
+        // `é` and `ö` are two bytes each in UTF-8, so byte offsets exceed the
+        // character offsets `#begin`/`#end` report.
+        assert_eq!(Some(7), data.match_begin().unwrap());
+        assert_eq!(Some(13), data.match_end().unwrap());
+    }
+
+    #[test]
+    fn begin_and_end_of_group_zero_agree_with_offset() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(
+                "m = /w\u{f6}rld/.match('h\u{e9}llo w\u{f6}rld'); [m.begin(0), m.end(0)] == m.offset(0)"
+                    .as_bytes(),
+            )
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn values_at_mixed_indices_and_names() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"m = /(?<a>\\d+) (?<b>\\w+)/.match('10 artichokes'); m.values_at(0, 1, 'b', :a)")
+            .unwrap();
+        let result = result.try_into_mut::<Vec<Option<&str>>>(&mut interp).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                Some("10 artichokes"),
+                Some("10"),
+                Some("artichokes"),
+                Some("10")
+            ]
+        );
+    }
+
+    #[test]
+    fn values_at_negative_and_out_of_range_indices() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"m = /(\\d+) (\\w+)/.match('10 artichokes'); m.values_at(-1, 99)")
+            .unwrap();
+        let result = result.try_into_mut::<Vec<Option<&str>>>(&mut interp).unwrap();
+        assert_eq!(result, vec![Some("artichokes"), None]);
+    }
+
+    #[test]
+    fn values_at_unknown_group_name() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp
+            .eval(b"m = /(?<a>\\d+)/.match('10'); m.values_at('nope')")
+            .unwrap_err();
+        assert_eq!("IndexError", err.name().as_ref());
+    }
+
+    #[test]
+    fn values_at_no_args() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"m = /(\\d+)/.match('10'); m.values_at")
+            .unwrap();
+        let result = result.try_into_mut::<Vec<Option<&str>>>(&mut interp).unwrap();
+        assert_eq!(result, Vec::<Option<&str>>::new());
+    }
+}
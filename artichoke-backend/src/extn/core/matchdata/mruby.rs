@@ -47,6 +47,11 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("string", artichoke_matchdata_string, sys::mrb_args_none())?
         .add_method("to_a", artichoke_matchdata_to_a, sys::mrb_args_none())?
         .add_method("to_s", artichoke_matchdata_to_s, sys::mrb_args_none())?
+        .add_method(
+            "values_at",
+            artichoke_matchdata_values_at,
+            sys::mrb_args_rest(),
+        )?
         .add_method("end", artichoke_matchdata_end, sys::mrb_args_req(1))?
         .define()?;
     interp.def_class::<matchdata::MatchData>(spec)?;
@@ -255,6 +260,22 @@ unsafe extern "C" fn artichoke_matchdata_to_a(
     }
 }
 
+unsafe extern "C" fn artichoke_matchdata_values_at(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let value = Value::from(slf);
+    let args = args.iter().copied().map(Value::from);
+    let result = trampoline::values_at(&mut guard, value, args);
+    match result {
+        Ok(result) => result.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn artichoke_matchdata_to_s(
     mrb: *mut sys::mrb_state,
     slf: sys::mrb_value,
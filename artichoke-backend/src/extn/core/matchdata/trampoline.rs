@@ -1,7 +1,7 @@
 use std::convert::TryFrom;
 
 use crate::extn::core::array::Array;
-use crate::extn::core::matchdata::{Capture, CaptureAt, CaptureExtract, MatchData};
+use crate::extn::core::matchdata::{Capture, CaptureAt, CaptureExtract, CaptureMatch, MatchData};
 use crate::extn::core::regexp::Regexp;
 use crate::extn::core::symbol::Symbol;
 use crate::extn::prelude::*;
@@ -25,7 +25,12 @@ pub fn begin(interp: &mut Artichoke, mut value: Value, mut at: Value) -> Result<
 pub fn captures(interp: &mut Artichoke, mut value: Value) -> Result<Value, Exception> {
     let data = unsafe { MatchData::unbox_from_value(&mut value, interp)? };
     if let Some(captures) = data.captures()? {
-        interp.try_convert_mut(captures)
+        let mut ary = Array::with_capacity(captures.len());
+        for capture in captures {
+            let capture = interp.convert_mut(capture);
+            ary.push(capture);
+        }
+        Array::alloc_value(ary, interp)
     } else {
         Ok(Value::nil())
     }
@@ -154,10 +159,58 @@ pub fn string(interp: &mut Artichoke, mut value: Value) -> Result<Value, Excepti
     Ok(string)
 }
 
+pub fn values_at<T>(interp: &mut Artichoke, mut value: Value, args: T) -> Result<Value, Exception>
+where
+    T: IntoIterator<Item = Value>,
+{
+    let data = unsafe { MatchData::unbox_from_value(&mut value, interp)? };
+    let mut values = Array::new();
+    for mut elem in args {
+        let at = if let Ok(index) = elem.implicitly_convert_to_int(interp) {
+            CaptureAt::GroupIndex(index)
+        } else if let Ok(name) = elem.implicitly_convert_to_string(interp) {
+            CaptureAt::GroupName(name)
+        } else if let Ok(symbol) = unsafe { Symbol::unbox_from_value(&mut elem, interp) } {
+            CaptureAt::GroupName(symbol.bytes(interp))
+        } else {
+            // NOTE(lopopolo): Encapsulation is broken here by reaching into the
+            // inner regexp.
+            let captures_len = data.regexp.inner().captures_len(None)?;
+            let rangelen = Int::try_from(captures_len)
+                .map_err(|_| ArgumentError::from("input string too long"))?;
+            if let Some(protect::Range { start, len }) = elem.is_range(interp, rangelen)? {
+                CaptureAt::StartLen(start, len)
+            } else {
+                values.push(Value::nil());
+                continue;
+            }
+        };
+        match data.capture_at(at)? {
+            CaptureMatch::None => values.push(Value::nil()),
+            CaptureMatch::Single(capture) => {
+                let capture = interp.try_convert_mut(capture)?;
+                values.push(capture);
+            }
+            CaptureMatch::Range(captures) => {
+                for capture in captures {
+                    let capture = interp.try_convert_mut(capture)?;
+                    values.push(capture);
+                }
+            }
+        }
+    }
+    Array::alloc_value(values, interp)
+}
+
 pub fn to_a(interp: &mut Artichoke, mut value: Value) -> Result<Value, Exception> {
     let data = unsafe { MatchData::unbox_from_value(&mut value, interp)? };
-    if let Some(ary) = data.to_a()? {
-        interp.try_convert_mut(ary)
+    if let Some(captures) = data.to_a()? {
+        let mut ary = Array::with_capacity(captures.len());
+        for capture in captures {
+            let capture = interp.convert_mut(capture);
+            ary.push(capture);
+        }
+        Array::alloc_value(ary, interp)
     } else {
         Ok(Value::nil())
     }
@@ -20,6 +20,7 @@ pub mod module;
 pub mod numeric;
 pub mod object;
 pub mod proc;
+pub mod process;
 #[cfg(feature = "core-random")]
 pub mod random;
 pub mod range;
@@ -56,6 +57,7 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     module::init(interp)?;
     object::init(interp)?;
     proc::init(interp)?;
+    process::mruby::init(interp)?;
     #[cfg(feature = "core-random")]
     random::mruby::init(interp)?;
     range::init(interp)?;
@@ -13,3 +13,97 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
 
 #[derive(Debug)]
 pub struct Module;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn attr_accessor_defines_getter_and_setter_pairs() {
+        let mut interp = crate::interpreter().unwrap();
+        let code = br#"
+class Point
+  attr_accessor :x, :y
+end
+point = Point.new
+point.x = 3
+point.y = 4
+[point.x, point.y, point.instance_variable_get(:@x)].inspect
+"#;
+        let result = interp.eval(code).unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("[3, 4, 3]", result);
+
+        let result = interp
+            .eval(b"%i[x x= y y=].all? { |m| Point.new.respond_to?(m) }")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn attr_reader_and_writer_define_one_direction() {
+        let mut interp = crate::interpreter().unwrap();
+        let code = br#"
+class Half
+  attr_reader :r
+  attr_writer :w
+end
+half = Half.new
+[half.respond_to?(:r), half.respond_to?(:r=), half.respond_to?(:w), half.respond_to?(:w=)].inspect
+"#;
+        let result = interp.eval(code).unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("[true, false, false, true]", result);
+    }
+
+    #[test]
+    fn attr_accessor_rejects_non_symbol_names() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp
+            .eval(b"class Broken; attr_accessor 42; end")
+            .unwrap_err();
+        assert_eq!("TypeError", err.name().as_ref());
+    }
+
+    #[test]
+    fn define_method_closes_over_captured_locals() {
+        let mut interp = crate::interpreter().unwrap();
+        let code = br#"
+class Greeter
+  greeting = 'hello'
+  define_method(:greet) { |name| "#{greeting}, #{name}" }
+end
+Greeter.new.greet('world')
+"#;
+        let result = interp.eval(code).unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("hello, world", result);
+    }
+
+    #[test]
+    fn define_method_accepts_an_explicit_proc_or_lambda() {
+        let mut interp = crate::interpreter().unwrap();
+        let code = br#"
+class Calc
+  define_method(:double, ->(x) { x * 2 })
+  define_method(:triple, proc { |x| x * 3 })
+end
+Calc.new.double(2) + Calc.new.triple(2)
+"#;
+        let result = interp.eval(code).unwrap();
+        assert_eq!(10, result.try_into::<Int>(&interp).unwrap());
+    }
+
+    #[test]
+    fn define_method_from_lambda_enforces_arity() {
+        let mut interp = crate::interpreter().unwrap();
+        let code = br#"
+class Strict
+  define_method(:exact, ->(x, y) { x + y })
+end
+Strict.new.exact(1)
+"#;
+        let err = interp.eval(code).unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+    }
+}
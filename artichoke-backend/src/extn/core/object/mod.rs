@@ -13,3 +13,38 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
 
 #[derive(Debug)]
 pub struct Object;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn respond_to_consults_respond_to_missing() {
+        let mut interp = crate::interpreter().unwrap();
+        let code = br#"
+class Dynamic
+  def method_missing(name, *args)
+    return "handled #{name}" if name.to_s.start_with?('dyn_')
+
+    super
+  end
+
+  def respond_to_missing?(name, include_private = false)
+    name.to_s.start_with?('dyn_') || super
+  end
+end
+obj = Dynamic.new
+[obj.respond_to?(:dyn_lookup), obj.respond_to?(:other), obj.dyn_lookup].inspect
+"#;
+        let result = interp.eval(code).unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!(r#"[true, false, "handled dyn_lookup"]"#, result);
+    }
+
+    #[test]
+    fn respond_to_missing_default_returns_false() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"Object.new.respond_to?(:no_such_method)").unwrap();
+        assert!(!result.try_into::<bool>(&interp).unwrap());
+    }
+}
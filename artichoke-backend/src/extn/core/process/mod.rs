@@ -0,0 +1,58 @@
+use crate::extn::prelude::*;
+
+pub mod mruby;
+pub mod trampoline;
+
+/// Clock id for the system-wide wall clock, `Process::CLOCK_REALTIME`.
+pub const CLOCK_REALTIME: Int = 0;
+
+/// Clock id for the monotonically increasing clock,
+/// `Process::CLOCK_MONOTONIC`.
+pub const CLOCK_MONOTONIC: Int = 1;
+
+#[derive(Debug)]
+pub struct Process;
+
+/// Read the given clock, returning elapsed seconds as a float.
+///
+/// Time is sourced from the interpreter's [pluggable
+/// clock](crate::state::clock::Clock), which makes this API deterministic when
+/// the clock is replaced with a mock.
+pub fn clock_gettime(interp: &Artichoke, clock_id: Int) -> Result<Fp, Exception> {
+    let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+    let duration = match clock_id {
+        CLOCK_REALTIME => state.clock.realtime(),
+        CLOCK_MONOTONIC => state.clock.monotonic(),
+        // NOTE: MRI returns `Errno::EINVAL` exception class.
+        _ => return Err(ArgumentError::from("Invalid argument - clock_gettime").into()),
+    };
+    Ok(duration.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn monotonic_reads_are_non_decreasing() {
+        let mut interp = crate::interpreter().unwrap();
+        let first = interp
+            .eval(b"Process.clock_gettime(Process::CLOCK_MONOTONIC)")
+            .unwrap()
+            .try_into::<Fp>(&interp)
+            .unwrap();
+        let second = interp
+            .eval(b"Process.clock_gettime(Process::CLOCK_MONOTONIC)")
+            .unwrap()
+            .try_into::<Fp>(&interp)
+            .unwrap();
+        assert!(second >= first);
+    }
+
+    #[test]
+    fn invalid_clock_id_raises_argument_error() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.eval(b"Process.clock_gettime(999)").unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+    }
+}
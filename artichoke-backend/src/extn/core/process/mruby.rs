@@ -0,0 +1,38 @@
+use crate::extn::core::process::{self, trampoline};
+use crate::extn::prelude::*;
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_module_defined::<process::Process>() {
+        return Ok(());
+    }
+    let spec = module::Spec::new(interp, "Process", None)?;
+    module::Builder::for_spec(interp, &spec)
+        .add_self_method(
+            "clock_gettime",
+            artichoke_process_clock_gettime,
+            sys::mrb_args_req(1),
+        )?
+        .define()?;
+    interp.def_module::<process::Process>(spec)?;
+    let clock_realtime = interp.convert(process::CLOCK_REALTIME);
+    interp.define_module_constant::<process::Process>("CLOCK_REALTIME", clock_realtime)?;
+    let clock_monotonic = interp.convert(process::CLOCK_MONOTONIC);
+    interp.define_module_constant::<process::Process>("CLOCK_MONOTONIC", clock_monotonic)?;
+    trace!("Patched Process onto interpreter");
+    Ok(())
+}
+
+unsafe extern "C" fn artichoke_process_clock_gettime(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let clock_id = mrb_get_args!(mrb, required = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let clock_id = Value::from(clock_id);
+    let result = trampoline::clock_gettime(&mut guard, clock_id);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
@@ -0,0 +1,8 @@
+use crate::extn::core::process;
+use crate::extn::prelude::*;
+
+pub fn clock_gettime(interp: &mut Artichoke, clock_id: Value) -> Result<Value, Exception> {
+    let clock_id = clock_id.implicitly_convert_to_int(interp)?;
+    let now = process::clock_gettime(interp, clock_id)?;
+    Ok(interp.convert_mut(now))
+}
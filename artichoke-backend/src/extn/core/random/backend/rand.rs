@@ -36,6 +36,16 @@ impl<T> Rand<T> {
     pub fn seed(&self) -> u64 {
         self.seed
     }
+
+    #[inline]
+    pub fn inner(&self) -> &T {
+        &self.rng
+    }
+
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.rng
+    }
 }
 
 impl<T> Rand<T>
@@ -87,6 +87,21 @@ pub fn urandom(size: Int) -> Result<Vec<u8>, Exception> {
     }
 }
 
+/// Coerce a `Range` bound to a `Float` for a float range draw.
+fn float_bound(interp: &mut Artichoke, bound: Value) -> Result<Fp, Exception> {
+    if let Ruby::Float = bound.ruby_type() {
+        Ok(bound.try_into(interp)?)
+    } else {
+        let bound = bound.implicitly_convert_to_int(interp).map_err(|_| {
+            let mut message = b"invalid argument - ".to_vec();
+            message.extend(bound.inspect(interp));
+            ArgumentError::from(message)
+        })?;
+        #[allow(clippy::cast_precision_loss)]
+        Ok(bound as Fp)
+    }
+}
+
 pub struct Random(Box<dyn backend::RandType>);
 
 impl fmt::Debug for Random {
@@ -186,6 +201,64 @@ impl Random {
         }
     }
 
+    /// Draw a random number from a `Range` argument to `Random#rand`.
+    ///
+    /// Returns `Ok(None)` when `range` is not a `Range` so callers can fall
+    /// back to scalar `max` handling. Integer ranges are extracted with
+    /// [`Value::is_range`], which folds exclusivity into the returned length.
+    /// Ranges with a `Float` bound draw from the half-open interval
+    /// `[begin, end)`. Empty and reversed ranges raise `ArgumentError`.
+    pub fn rand_range(
+        &mut self,
+        interp: &mut Artichoke,
+        range: Value,
+    ) -> Result<Option<RandomNumber>, Exception> {
+        if !matches!(range.ruby_type(), Ruby::Range) {
+            return Ok(None);
+        }
+        let begin = range.funcall(interp, "begin", &[], None)?;
+        let end = range.funcall(interp, "end", &[], None)?;
+        if matches!(begin.ruby_type(), Ruby::Float) || matches!(end.ruby_type(), Ruby::Float) {
+            let start = float_bound(interp, begin)?;
+            let end = float_bound(interp, end)?;
+            let len = end - start;
+            if !len.is_finite() {
+                // NOTE: MRI returns `Errno::EDOM` exception class.
+                return Err(ArgumentError::from("Numerical argument out of domain").into());
+            }
+            if len > 0.0 {
+                let number = self.inner_mut().rand_float(interp, Some(len))?;
+                return Ok(Some(RandomNumber::Float(start + number)));
+            }
+        } else if begin.implicitly_convert_to_int(interp)? < 0 {
+            // `mrb_range_beg_len` treats a negative start as an offset from
+            // the end of a container, which has no meaning for a numeric
+            // draw, so compute the span directly.
+            let start = begin.implicitly_convert_to_int(interp)?;
+            let end = end.implicitly_convert_to_int(interp)?;
+            let exclusive = range.funcall(interp, "exclude_end?", &[], None)?;
+            let len = if exclusive.is_truthy() {
+                end - start
+            } else {
+                end - start + 1
+            };
+            if len > 0 {
+                let number = self.inner_mut().rand_int(interp, len)?;
+                return Ok(Some(RandomNumber::Integer(start + number)));
+            }
+        } else if let Some(extracted) = range.is_range(interp, Int::max_value())? {
+            if extracted.len > 0 {
+                let number = self.inner_mut().rand_int(interp, extracted.len)?;
+                return Ok(Some(RandomNumber::Integer(extracted.start + number)));
+            }
+        }
+        // Reversed integer ranges fail extraction; empty ranges like `5...5`
+        // extract with a zero length. Both are invalid draws.
+        let mut message = b"invalid argument - ".to_vec();
+        message.extend(range.inspect(interp));
+        Err(ArgumentError::from(message).into())
+    }
+
     #[inline]
     pub fn seed(&self, interp: &mut Artichoke) -> Result<Int, Exception> {
         let seed = self.inner().seed(interp)?;
@@ -262,3 +335,95 @@ impl ConvertMut<RandomNumber, Value> for Artichoke {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn default_seed_reflects_interpreter_seed() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"Random::DEFAULT.seed").unwrap();
+        let seed = result.try_into::<Int>(&interp).unwrap();
+        #[allow(clippy::cast_possible_wrap)]
+        let prng_seed = interp.prng_seed().unwrap() as Int;
+        assert_eq!(seed, prng_seed);
+    }
+
+    #[test]
+    fn default_bytes_returns_requested_length() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"Random::DEFAULT.bytes(5)").unwrap();
+        let bytes = result.try_into_mut::<Vec<u8>>(&mut interp).unwrap();
+        assert_eq!(bytes.len(), 5);
+    }
+
+    #[test]
+    fn bytes_zero_returns_empty_string() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"Random.new(33).bytes(0)").unwrap();
+        let bytes = result.try_into_mut::<Vec<u8>>(&mut interp).unwrap();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn rand_inclusive_integer_range_stays_in_bounds() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"r = Random.new(33); nums = 200.times.map { r.rand(1..3) }; nums.all? { |n| (1..3).cover?(n) } && nums.include?(3)")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn rand_exclusive_integer_range_excludes_endpoint() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"r = Random.new(33); 200.times.map { r.rand(1...3) }.all? { |n| n == 1 || n == 2 }")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn rand_negative_integer_range_stays_in_bounds() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"r = Random.new(33); 200.times.map { r.rand(-5..-1) }.all? { |n| (-5..-1).cover?(n) }")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn rand_empty_exclusive_range_raises_argument_error() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.eval(b"Random.new(33).rand(5...5)").unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+    }
+
+    #[test]
+    fn rand_reversed_range_raises_argument_error() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.eval(b"Random.new(33).rand(6..1)").unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+    }
+
+    #[test]
+    fn rand_float_range_is_half_open() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"r = Random.new(33); 200.times.map { r.rand(1.5..2.5) }.all? { |n| n >= 1.5 && n < 2.5 }")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn bytes_negative_length_raises_argument_error() {
+        let mut interp = crate::interpreter().unwrap();
+        let err = interp.eval(b"Random.new(33).bytes(-1)").unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+        assert_eq!(
+            &b"negative string size (or size too big)"[..],
+            err.message().as_ref()
+        );
+    }
+}
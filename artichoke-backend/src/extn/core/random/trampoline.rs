@@ -31,6 +31,11 @@ pub fn rand(
     max: Option<Value>,
 ) -> Result<Value, Exception> {
     let mut rand = unsafe { Random::unbox_from_value(&mut rand, interp)? };
+    if let Some(arg) = max {
+        if let Some(num) = rand.rand_range(interp, arg)? {
+            return Ok(interp.convert_mut(num));
+        }
+    }
     let max = interp.try_convert_mut(max)?;
     let num = rand.rand(interp, max)?;
     Ok(interp.convert_mut(num))
@@ -123,7 +123,10 @@ impl RegexpType for Onig {
                 .map(|captures| captures.len())
                 .unwrap_or_default()
         } else {
-            self.regex.captures_len()
+            // `onig_number_of_captures` excludes the implicit group 0 for the
+            // whole match; count it to match the regex crate backend and the
+            // length reported for an actual match above.
+            self.regex.captures_len() + 1
         };
         Ok(result)
     }
@@ -365,6 +365,36 @@ impl Regexp {
         self.0.names()
     }
 
+    /// Return capture group names in index order without running a match.
+    ///
+    /// The returned vector has one entry per capture group, indexed by group
+    /// number. Group 0, the implicit group for the whole match, and anonymous
+    /// groups are `None`. A name bound to multiple groups appears at every
+    /// index it is bound to.
+    ///
+    /// Unlike [`names`](Self::names), which deduplicates and orders names by
+    /// first appearance for `Regexp#names`, this API preserves the mapping
+    /// from group number to name.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying regexp backend fails to compute the group count or
+    /// resolve group names, an error is returned.
+    pub fn capture_names(&self) -> Result<Vec<Option<Vec<u8>>>, Exception> {
+        let len = self.0.captures_len(None)?;
+        let mut names = vec![None; len];
+        for (name, indexes) in self.0.named_captures()? {
+            for index in indexes {
+                if let Some(slot) = names.get_mut(index) {
+                    if slot.is_none() {
+                        *slot = Some(name.clone());
+                    }
+                }
+            }
+        }
+        Ok(names)
+    }
+
     #[inline]
     #[must_use]
     pub fn options(&self) -> Int {
@@ -434,3 +464,38 @@ impl TryConvertMut<(Option<Value>, Option<Value>), (Option<opts::Options>, Optio
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Regexp;
+    use crate::test::prelude::*;
+
+    #[test]
+    fn capture_names_are_indexed_by_group_number() {
+        let mut interp = crate::interpreter().unwrap();
+        let mut value = interp.eval(b"/(?<a>\\d+)(\\w)(?<b>\\s)/").unwrap();
+        let regexp = unsafe { Regexp::unbox_from_value(&mut value, &mut interp).unwrap() };
+        let names = regexp.capture_names().unwrap();
+        assert_eq!(
+            vec![None, Some(b"a".to_vec()), None, Some(b"b".to_vec())],
+            names
+        );
+    }
+
+    #[test]
+    fn capture_names_without_named_groups() {
+        let mut interp = crate::interpreter().unwrap();
+        let mut value = interp.eval(b"/(\\d+) (\\w+)/").unwrap();
+        let regexp = unsafe { Regexp::unbox_from_value(&mut value, &mut interp).unwrap() };
+        let names = regexp.capture_names().unwrap();
+        assert_eq!(vec![None, None, None], names);
+    }
+
+    #[test]
+    fn names_deduplicates_in_first_appearance_order() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"/(?<b>\\d)(?<a>\\d)(?<b>\\d)/.names").unwrap();
+        let names = result.try_into_mut::<Vec<&str>>(&mut interp).unwrap();
+        assert_eq!(vec!["b", "a"], names);
+    }
+}
@@ -437,8 +437,12 @@ class String
   end
 
   def gsub!(pattern, replacement = nil, &blk)
+    raise FrozenError, "can't modify frozen String" if frozen?
+
     replaced = gsub(pattern, replacement, &blk)
-    self[0..-1] = replaced unless self == replaced
+    return nil if self == replaced
+
+    self[0..-1] = replaced
     self
   end
 
@@ -479,8 +483,13 @@ class String
   end
 
   def lstrip!
+    raise FrozenError, "can't modify frozen String" if frozen?
+
     replaced = lstrip
-    self[0..-1] = replaced unless self == replaced
+    return nil if self == replaced
+
+    self[0..-1] = replaced
+    self
   end
 
   def match(pattern, pos = 0)
@@ -548,8 +557,13 @@ class String
   end
 
   def rstrip!
+    raise FrozenError, "can't modify frozen String" if frozen?
+
     replaced = rstrip
-    self[0..-1] = replaced unless self == replaced
+    return nil if self == replaced
+
+    self[0..-1] = replaced
+    self
   end
 
   def scrub
@@ -630,8 +644,13 @@ class String
   end
 
   def strip!
+    raise FrozenError, "can't modify frozen String" if frozen?
+
     replaced = strip
-    self[0..-1] = replaced unless self == replaced
+    return nil if self == replaced
+
+    self[0..-1] = replaced
+    self
   end
 
   def sub(pattern, replacement = nil)
@@ -660,8 +679,13 @@ class String
   end
 
   def sub!(pattern, replacement = nil, &blk)
+    raise FrozenError, "can't modify frozen String" if frozen?
+
     replaced = sub(pattern, replacement, &blk)
-    self[0..-1] = replaced unless self == replaced
+    return nil if self == replaced
+
+    self[0..-1] = replaced
+    self
   end
 
   def sum
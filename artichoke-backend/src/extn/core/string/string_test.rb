@@ -4,8 +4,13 @@
 # https://ruby-doc.org/core-2.6.3/String.html
 def spec
   string_match_operator
+  string_element_reference
   string_element_reference_regexp
+  string_element_reference_slice_alias
   string_scan
+  string_bang_methods_return_nil_when_unchanged
+  string_bang_methods_mutate_in_place
+  string_bang_methods_raise_on_frozen_receiver
   string_unary_minus
 
   true
@@ -19,6 +24,23 @@ def string_match_operator
   raise unless match.nil?
 end
 
+def string_element_reference
+  s = 'hello there'
+  raise unless s[1] == 'e'
+  raise unless s[-2] == 'r'
+  raise unless s[100].nil?
+  raise unless s[1, 3] == 'ell'
+  raise unless s[1, 0] == ''
+  raise unless s[1, -1].nil?
+  raise unless s[100, 2].nil?
+  raise unless s[1..3] == 'ell'
+  raise unless s[1...3] == 'el'
+  raise unless s[-4..-2] == 'her'
+  raise unless s[100..102].nil?
+  raise unless s['lo'] == 'lo'
+  raise unless s['bye'].nil?
+end
+
 def string_element_reference_regexp
   raise unless 'hello there'[/[aeiou](.)\1/] == 'ell'
   raise unless 'hello there'[/[aeiou](.)\1/, 0] == 'ell'
@@ -28,6 +50,17 @@ def string_element_reference_regexp
   raise unless 'hello there'[/(?<vowel>[aeiou])(?<non_vowel>[^aeiou])/, 'vowel'] == 'e'
 end
 
+def string_element_reference_slice_alias
+  s = 'hello there'
+  raise unless s.slice(1) == 'e'
+  raise unless s.slice(1, 3) == 'ell'
+  raise unless s.slice(1..3) == 'ell'
+  raise unless s.slice(/[aeiou](.)\1/) == 'ell'
+  raise unless s.slice(/[aeiou](.)\1/, 1) == 'l'
+  raise unless s.slice('lo') == 'lo'
+  raise unless s.slice('bye').nil?
+end
+
 def string_scan
   s = 'abababa'
   raise unless s.scan(/./) == %w[a b a b a b a]
@@ -36,6 +69,75 @@ def string_scan
   raise unless s.scan('no no no') == []
 end
 
+def string_bang_methods_return_nil_when_unchanged
+  raise unless 'hello'.dup.sub!(/z/, 'y').nil?
+  raise unless 'hello'.dup.gsub!(/z/, 'y').nil?
+  raise unless 'HELLO'.dup.upcase!.nil?
+  raise unless 'hello'.dup.downcase!.nil?
+  raise unless 'hello'.dup.chomp!.nil?
+  raise unless 'hello'.dup.strip!.nil?
+  raise unless 'hello'.dup.lstrip!.nil?
+  raise unless 'hello'.dup.rstrip!.nil?
+end
+
+def string_bang_methods_mutate_in_place
+  s = 'hello'.dup
+  raise unless s.sub!(/l/, 'y').equal?(s)
+  raise unless s == 'heylo'
+
+  s = 'hello'.dup
+  raise unless s.gsub!(/l/, 'y').equal?(s)
+  raise unless s == 'heyyo'
+
+  s = 'hello'.dup
+  raise unless s.upcase! == 'HELLO'
+  raise unless s == 'HELLO'
+
+  s = "hello\n".dup
+  raise unless s.chomp! == 'hello'
+  raise unless s == 'hello'
+
+  s = '  hello  '.dup
+  raise unless s.strip!.equal?(s)
+  raise unless s == 'hello'
+
+  s = '  hello'.dup
+  raise unless s.lstrip!.equal?(s)
+  raise unless s == 'hello'
+
+  s = 'hello  '.dup
+  raise unless s.rstrip!.equal?(s)
+  raise unless s == 'hello'
+end
+
+def string_bang_methods_raise_on_frozen_receiver
+  %i[strip! lstrip! rstrip!].each do |method|
+    raised = false
+    begin
+      ' frozen '.freeze.send(method)
+    rescue FrozenError
+      raised = true
+    end
+    raise unless raised
+  end
+
+  raised = false
+  begin
+    'frozen'.freeze.sub!(/z/, 'y')
+  rescue FrozenError
+    raised = true
+  end
+  raise unless raised
+
+  raised = false
+  begin
+    'frozen'.freeze.gsub!(/z/, 'y')
+  rescue FrozenError
+    raised = true
+  end
+  raise unless raised
+end
+
 def string_unary_minus
   s = -'abababa'
   raise unless s.frozen?
@@ -152,3 +152,43 @@ impl BoxUnboxVmValue for Symbol {
         let _ = data;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn ascii_name_round_trips_through_to_sym_and_to_s() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp.eval(b"'foo'.to_sym.to_s == 'foo'").unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"'foo'.intern.to_s == 'foo'").unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn binary_name_round_trips_without_loss() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"name = \"\\xff\\xfebinary\\x00tail\"; name.to_sym.to_s == name")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"\"\\xff\\xfebinary\\x00tail\".to_sym.to_s.bytes == [0xff, 0xfe, 0x62, 0x69, 0x6e, 0x61, 0x72, 0x79, 0x00, 0x74, 0x61, 0x69, 0x6c]")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn symbols_from_equal_byte_strings_are_identical() {
+        let mut interp = crate::interpreter().unwrap();
+        let result = interp
+            .eval(b"a = 'equal_name'.to_sym; b = ('equal_' + 'name').to_sym; a.equal?(b)")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"a = \"\\xc3\\x28\".to_sym; b = \"\\xc3\\x28\".to_sym; a.equal?(b)")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+    }
+}
@@ -11,6 +11,17 @@ use prelude::*;
 pub const INPUT_RECORD_SEPARATOR: &str = "\n";
 
 pub fn init<T>(interp: &mut Artichoke, config: T) -> InitializeResult<()>
+where
+    T: ReleaseMetadata,
+{
+    init_with_stdlib(interp, config, stdlib::Config::default())
+}
+
+pub fn init_with_stdlib<T>(
+    interp: &mut Artichoke,
+    config: T,
+    stdlib_config: stdlib::Config,
+) -> InitializeResult<()>
 where
     T: ReleaseMetadata,
 {
@@ -53,6 +64,6 @@ where
     interp.define_global_constant("ARTICHOKE_COMPILER_VERSION", compiler_version)?;
 
     core::init(interp)?;
-    stdlib::init(interp)?;
+    stdlib::init_with_config(interp, stdlib_config)?;
     Ok(())
 }
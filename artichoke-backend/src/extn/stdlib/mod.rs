@@ -16,21 +16,99 @@ pub mod strscan;
 pub mod time;
 pub mod uri;
 
+/// Per-package toggles for standard library initialization.
+///
+/// Disabled packages skip `init` registration entirely, so their sources
+/// cannot be `require`d on the resulting interpreter. All packages are
+/// enabled by default, which matches [`init`].
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub abbrev: bool,
+    pub base64: bool,
+    pub cmath: bool,
+    pub delegate: bool,
+    pub forwardable: bool,
+    pub json: bool,
+    pub monitor: bool,
+    pub ostruct: bool,
+    pub securerandom: bool,
+    pub set: bool,
+    pub shellwords: bool,
+    pub strscan: bool,
+    pub time: bool,
+    pub uri: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            abbrev: true,
+            base64: true,
+            cmath: true,
+            delegate: true,
+            forwardable: true,
+            json: true,
+            monitor: true,
+            ostruct: true,
+            securerandom: true,
+            set: true,
+            shellwords: true,
+            strscan: true,
+            time: true,
+            uri: true,
+        }
+    }
+}
+
 pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
-    abbrev::init(interp)?;
-    base64::init(interp)?;
-    cmath::init(interp)?;
-    delegate::init(interp)?;
-    forwardable::init(interp)?;
-    json::init(interp)?;
-    monitor::init(interp)?;
-    ostruct::init(interp)?;
+    init_with_config(interp, Config::default())
+}
+
+pub fn init_with_config(interp: &mut Artichoke, config: Config) -> InitializeResult<()> {
+    if config.abbrev {
+        abbrev::init(interp)?;
+    }
+    if config.base64 {
+        base64::init(interp)?;
+    }
+    if config.cmath {
+        cmath::init(interp)?;
+    }
+    if config.delegate {
+        delegate::init(interp)?;
+    }
+    if config.forwardable {
+        forwardable::init(interp)?;
+    }
+    if config.json {
+        json::init(interp)?;
+    }
+    if config.monitor {
+        monitor::init(interp)?;
+    }
+    if config.ostruct {
+        ostruct::init(interp)?;
+    }
     #[cfg(feature = "stdlib-securerandom")]
-    securerandom::mruby::init(interp)?;
-    set::init(interp)?;
-    shellwords::init(interp)?;
-    strscan::init(interp)?;
-    time::init(interp)?;
-    uri::init(interp)?;
+    {
+        if config.securerandom {
+            securerandom::mruby::init(interp)?;
+        }
+    }
+    if config.set {
+        set::init(interp)?;
+    }
+    if config.shellwords {
+        shellwords::init(interp)?;
+    }
+    if config.strscan {
+        strscan::init(interp)?;
+    }
+    if config.time {
+        time::init(interp)?;
+    }
+    if config.uri {
+        uri::init(interp)?;
+    }
     Ok(())
 }
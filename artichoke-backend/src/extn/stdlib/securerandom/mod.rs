@@ -1,7 +1,8 @@
 use rand::distributions::Alphanumeric;
 use rand::{self, Rng, RngCore};
 use std::convert::TryFrom;
-use uuid::Uuid;
+use std::fmt;
+use uuid::{Builder, Uuid, Variant, Version};
 
 use crate::extn::prelude::*;
 
@@ -10,6 +11,36 @@ pub mod trampoline;
 
 const DEFAULT_REQUESTED_BYTES: usize = 16;
 
+/// An interpreter-injectable entropy source backing `SecureRandom`.
+///
+/// Implemented for every [`RngCore`] that is [`Debug`](fmt::Debug), so RNGs
+/// from the `rand` crate can be installed directly with
+/// [`Artichoke::set_secure_random_source`](crate::Artichoke::set_secure_random_source).
+///
+/// Overriding the entropy source is intended for deterministic tests and for
+/// platforms without an OS RNG. Deterministic sources make every
+/// `SecureRandom` API predictable and must not be used in production
+/// security contexts.
+pub trait EntropySource: RngCore + fmt::Debug {}
+
+impl<T> EntropySource for T where T: RngCore + fmt::Debug {}
+
+/// Fill `buf` from the interpreter's installed [`EntropySource`], falling
+/// back to the OS-backed thread RNG when no override is installed.
+fn fill_bytes(interp: &mut Artichoke, buf: &mut [u8]) -> Result<(), Exception> {
+    let state = interp.state.as_mut().ok_or(InterpreterExtractError)?;
+    if let Some(ref mut source) = state.secure_random {
+        source
+            .try_fill_bytes(buf)
+            .map_err(|err| RuntimeError::from(err.to_string()))?;
+    } else {
+        rand::thread_rng()
+            .try_fill_bytes(buf)
+            .map_err(|err| RuntimeError::from(err.to_string()))?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     fn rng_must_be_cryptographically_secure<T>(_rng: T)
@@ -22,6 +53,168 @@ mod tests {
     fn rand_thread_rng_must_be_cryptographically_secure() {
         rng_must_be_cryptographically_secure(rand::thread_rng())
     }
+
+    #[test]
+    fn urlsafe_base64_uses_urlsafe_alphabet() {
+        let mut interp = crate::interpreter().unwrap();
+        let no_pad = super::urlsafe_base64(&mut interp, Some(10), false).unwrap();
+        assert!(no_pad.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        let padded = super::urlsafe_base64(&mut interp, Some(10), true).unwrap();
+        assert!(padded.ends_with('='));
+    }
+
+    #[test]
+    fn seeded_source_makes_hex_reproducible() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        use crate::test::prelude::*;
+
+        let mut interp = crate::interpreter().unwrap();
+        interp.set_secure_random_source(Box::new(StdRng::seed_from_u64(33)));
+        let _ = interp.eval(b"require 'securerandom'").unwrap();
+        let first = interp.eval(b"SecureRandom.hex(4)").unwrap();
+        let first = first.try_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(8, first.len());
+
+        let mut interp = crate::interpreter().unwrap();
+        interp.set_secure_random_source(Box::new(StdRng::seed_from_u64(33)));
+        let _ = interp.eval(b"require 'securerandom'").unwrap();
+        let second = interp.eval(b"SecureRandom.hex(4)").unwrap();
+        let second = second.try_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn uuid_v7_version_and_variant_bits() {
+        let mut interp = crate::interpreter().unwrap();
+        let uuid = super::uuid_v7(&mut interp).unwrap();
+        assert_eq!(36, uuid.len());
+        // The `ver` field is the 13th hex digit; version 7 is time-ordered.
+        assert_eq!(Some("7"), uuid.get(14..15));
+        // The `var` field occupies the two high bits of the 17th hex digit,
+        // which RFC 9562 fixes to `0b10`.
+        let variant = uuid.get(19..20).unwrap().chars().next().unwrap();
+        assert!(matches!(variant, '8' | '9' | 'a' | 'b'));
+    }
+
+    #[test]
+    fn uuid_v7_sorts_by_timestamp_prefix() {
+        use std::time::Duration;
+
+        use crate::state::clock::{Clock, Mock};
+
+        let mut interp = crate::interpreter().unwrap();
+        interp.state.as_mut().unwrap().clock = Box::new(Mock::new());
+        let first = super::uuid_v7(&mut interp).unwrap();
+        interp
+            .state
+            .as_mut()
+            .unwrap()
+            .clock
+            .sleep(Duration::from_millis(5));
+        let second = super::uuid_v7(&mut interp).unwrap();
+        // The leading 48 bits are a millisecond timestamp, so UUIDs from
+        // different milliseconds order lexicographically by creation time.
+        assert!(first.get(..13) < second.get(..13));
+        assert!(first < second);
+    }
+
+    #[test]
+    fn seeded_source_backs_uuid() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut interp = crate::interpreter().unwrap();
+        interp.set_secure_random_source(Box::new(StdRng::seed_from_u64(33)));
+        let first = super::uuid(&mut interp).unwrap();
+
+        let mut interp = crate::interpreter().unwrap();
+        interp.set_secure_random_source(Box::new(StdRng::seed_from_u64(33)));
+        let second = super::uuid(&mut interp).unwrap();
+        assert_eq!(first, second);
+        // Version and variant bits are stamped over the injected entropy.
+        assert_eq!(first.get(14..15), Some("4"));
+    }
+
+    #[test]
+    fn choose_picks_bytes_from_source() {
+        let chosen = super::choose(b"abc", 32).unwrap();
+        assert_eq!(chosen.len(), 32);
+        assert!(chosen.iter().all(|byte| b"abc".contains(byte)));
+    }
+
+    #[test]
+    fn choose_zero_length() {
+        assert_eq!(super::choose(b"abc", 0).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn choose_negative_length() {
+        assert!(super::choose(b"abc", -1).is_err());
+    }
+
+    #[test]
+    fn choose_empty_source() {
+        assert!(super::choose(b"", 1).is_err());
+    }
+
+    #[test]
+    fn urlsafe_base64_negative_length() {
+        let mut interp = crate::interpreter().unwrap();
+        assert!(super::urlsafe_base64(&mut interp, Some(-1), false).is_err());
+    }
+
+    #[test]
+    fn base64_length_is_byte_count_not_output_length() {
+        use std::convert::TryFrom;
+
+        let mut interp = crate::interpreter().unwrap();
+        for len in &[0_i64, 1, 2, 3, 10, 16] {
+            let encoded = super::base64(&mut interp, Some(*len)).unwrap();
+            let decoded = base64::decode(&encoded).unwrap();
+            assert_eq!(decoded.len(), usize::try_from(*len).unwrap());
+        }
+    }
+
+    #[test]
+    fn base64_zero_length() {
+        let mut interp = crate::interpreter().unwrap();
+        assert_eq!(super::base64(&mut interp, Some(0)).unwrap(), "");
+    }
+
+    #[test]
+    fn base64_negative_length() {
+        let mut interp = crate::interpreter().unwrap();
+        assert!(super::base64(&mut interp, Some(-1)).is_err());
+    }
+
+    #[test]
+    fn base64_retains_padding() {
+        let mut interp = crate::interpreter().unwrap();
+        let encoded = super::base64(&mut interp, Some(1)).unwrap();
+        assert!(encoded.ends_with("=="));
+    }
+
+    #[test]
+    fn hex_returns_two_chars_per_byte() {
+        let mut interp = crate::interpreter().unwrap();
+        let hex = super::hex(&mut interp, Some(21)).unwrap();
+        assert_eq!(hex.len(), 42);
+        assert!(hex.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn hex_zero_length() {
+        let mut interp = crate::interpreter().unwrap();
+        assert_eq!(super::hex(&mut interp, Some(0)).unwrap(), "");
+    }
+
+    #[test]
+    fn hex_negative_length() {
+        let mut interp = crate::interpreter().unwrap();
+        assert!(super::hex(&mut interp, Some(-1)).is_err());
+    }
 }
 
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -35,7 +228,7 @@ impl SecureRandom {
     }
 }
 
-pub fn random_bytes(len: Option<Int>) -> Result<Vec<u8>, Exception> {
+pub fn random_bytes(interp: &mut Artichoke, len: Option<Int>) -> Result<Vec<u8>, Exception> {
     let len = if let Some(len) = len {
         match usize::try_from(len) {
             Ok(0) => return Ok(Vec::new()),
@@ -47,10 +240,8 @@ pub fn random_bytes(len: Option<Int>) -> Result<Vec<u8>, Exception> {
     } else {
         DEFAULT_REQUESTED_BYTES
     };
-    let mut rng = rand::thread_rng();
     let mut bytes = vec![0; len];
-    rng.try_fill_bytes(&mut bytes)
-        .map_err(|err| RuntimeError::from(err.to_string()))?;
+    fill_bytes(interp, &mut bytes)?;
     Ok(bytes)
 }
 
@@ -145,17 +336,60 @@ pub fn random_number(max: RandomNumberMax) -> Result<RandomNumber, ArgumentError
 }
 
 #[inline]
-pub fn hex(len: Option<Int>) -> Result<String, Exception> {
-    let bytes = random_bytes(len)?;
+pub fn hex(interp: &mut Artichoke, len: Option<Int>) -> Result<String, Exception> {
+    let bytes = random_bytes(interp, len)?;
     Ok(hex::encode(bytes))
 }
 
+/// Generate a base64-encoded `String` of random bytes.
+///
+/// `len` is the number of random *bytes* to generate, not the length of the
+/// returned string: encoding expands the output to `4 * ceil(len / 3)`
+/// characters including `=` padding. A `len` of 0 returns an empty string.
+/// When `len` is `None`, 16 random bytes are generated.
+///
+/// # Errors
+///
+/// If `len` is negative, an `ArgumentError` is returned.
 #[inline]
-pub fn base64(len: Option<Int>) -> Result<String, Exception> {
-    let bytes = random_bytes(len)?;
+pub fn base64(interp: &mut Artichoke, len: Option<Int>) -> Result<String, Exception> {
+    let bytes = random_bytes(interp, len)?;
     Ok(base64::encode(bytes))
 }
 
+#[inline]
+pub fn urlsafe_base64(
+    interp: &mut Artichoke,
+    len: Option<Int>,
+    padding: bool,
+) -> Result<String, Exception> {
+    let bytes = random_bytes(interp, len)?;
+    let config = if padding {
+        base64::URL_SAFE
+    } else {
+        base64::URL_SAFE_NO_PAD
+    };
+    Ok(base64::encode_config(bytes, config))
+}
+
+pub fn choose(source: &[u8], len: Int) -> Result<Vec<u8>, Exception> {
+    let len = match usize::try_from(len) {
+        Ok(0) => return Ok(Vec::new()),
+        Ok(len) => len,
+        Err(_) => return Err(ArgumentError::from("negative string size (or size too big)").into()),
+    };
+    if source.is_empty() {
+        return Err(ArgumentError::from("cannot choose from an empty source").into());
+    }
+    let mut rng = rand::thread_rng();
+    let mut bytes = Vec::with_capacity(len);
+    for _ in 0..len {
+        let idx = rng.gen_range(0, source.len());
+        bytes.push(source[idx]);
+    }
+    Ok(bytes)
+}
+
 pub fn alphanumeric(len: Option<Int>) -> Result<String, Exception> {
     let len = if let Some(len) = len {
         match usize::try_from(len) {
@@ -173,10 +407,41 @@ pub fn alphanumeric(len: Option<Int>) -> Result<String, Exception> {
     Ok(string)
 }
 
-#[must_use]
-pub fn uuid() -> String {
-    let uuid = Uuid::new_v4();
+pub fn uuid(interp: &mut Artichoke) -> Result<String, Exception> {
+    let mut bytes = [0; 16];
+    fill_bytes(interp, &mut bytes)?;
+    // Stamp the version and variant bits onto the random payload, which is
+    // exactly how `Uuid::new_v4` constructs a version 4 UUID.
+    let uuid = Builder::from_bytes(bytes)
+        .set_version(Version::Random)
+        .set_variant(Variant::RFC4122)
+        .build();
+    let mut buf = Uuid::encode_buffer();
+    let enc = uuid.to_hyphenated().encode_lower(&mut buf);
+    Ok(String::from(enc))
+}
+
+/// Generate an RFC 9562 version 7 (time-ordered) UUID.
+///
+/// The leading 48 bits are a big-endian Unix timestamp in milliseconds, so
+/// UUIDs generated in sequence sort lexicographically by creation time. The
+/// remaining 74 bits are drawn from the secure RNG. The timestamp is read
+/// from the interpreter [`Clock`](crate::state::clock::Clock).
+pub fn uuid_v7(interp: &mut Artichoke) -> Result<String, Exception> {
+    let mut bytes = [0; 16];
+    fill_bytes(interp, &mut bytes)?;
+    let state = interp.state.as_ref().ok_or(InterpreterExtractError)?;
+    let timestamp = state.clock.realtime().as_millis();
+    // The low 48 bits of the millisecond timestamp occupy the leading six
+    // bytes. `u128::to_be_bytes` yields 16 bytes; the last six hold them.
+    bytes[..6].copy_from_slice(&timestamp.to_be_bytes()[10..]);
+    // Stamp the `ver` field with 7 and the `var` field with `0b10`. The
+    // `uuid` crate's `Builder` has no version 7 variant, so set the bits
+    // directly per RFC 9562 section 5.7.
+    bytes[6] = (bytes[6] & 0x0f) | 0x70;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let uuid = Uuid::from_bytes(bytes);
     let mut buf = Uuid::encode_buffer();
     let enc = uuid.to_hyphenated().encode_lower(&mut buf);
-    String::from(enc)
+    Ok(String::from(enc))
 }
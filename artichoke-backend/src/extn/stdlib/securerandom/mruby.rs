@@ -29,7 +29,13 @@ impl File for SecureRandomFile {
                 artichoke_securerandom_base64,
                 sys::mrb_args_opt(1),
             )?
+            .add_self_method(
+                "choose",
+                artichoke_securerandom_choose,
+                sys::mrb_args_req(2),
+            )?
             .add_self_method("hex", artichoke_securerandom_hex, sys::mrb_args_opt(1))?
+            .add_self_method("rand", artichoke_securerandom_rand, sys::mrb_args_opt(1))?
             .add_self_method(
                 "random_bytes",
                 artichoke_securerandom_random_bytes,
@@ -40,7 +46,17 @@ impl File for SecureRandomFile {
                 artichoke_securerandom_random_number,
                 sys::mrb_args_opt(1),
             )?
+            .add_self_method(
+                "urlsafe_base64",
+                artichoke_securerandom_urlsafe_base64,
+                sys::mrb_args_opt(2),
+            )?
             .add_self_method("uuid", artichoke_securerandom_uuid, sys::mrb_args_none())?
+            .add_self_method(
+                "uuid_v7",
+                artichoke_securerandom_uuid_v7,
+                sys::mrb_args_none(),
+            )?
             .define()?;
         interp.def_module::<securerandom::SecureRandom>(spec)?;
 
@@ -57,7 +73,7 @@ unsafe extern "C" fn artichoke_securerandom_alphanumeric(
     let len = mrb_get_args!(mrb, optional = 1);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
-    let len = len.map(Value::from).and_then(|len| guard.convert(len));
+    let len = Value::from_optional_arg(len);
     let result = trampoline::alphanumeric(&mut guard, len);
     match result {
         Ok(value) => value.inner(),
@@ -73,7 +89,7 @@ unsafe extern "C" fn artichoke_securerandom_base64(
     let len = mrb_get_args!(mrb, optional = 1);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
-    let len = len.map(Value::from).and_then(|len| guard.convert(len));
+    let len = Value::from_optional_arg(len);
     let result = trampoline::base64(&mut guard, len);
     match result {
         Ok(value) => value.inner(),
@@ -81,6 +97,39 @@ unsafe extern "C" fn artichoke_securerandom_base64(
     }
 }
 
+#[no_mangle]
+unsafe extern "C" fn artichoke_securerandom_choose(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (source, len) = mrb_get_args!(mrb, required = 2);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let source = Value::from(source);
+    let len = Value::from(len);
+    let result = trampoline::choose(&mut guard, source, len);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_securerandom_rand(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let max = mrb_get_args!(mrb, optional = 1);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let max = Value::from_optional_arg(max);
+    let result = trampoline::rand(&mut guard, max);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
 #[no_mangle]
 unsafe extern "C" fn artichoke_securerandom_hex(
     mrb: *mut sys::mrb_state,
@@ -89,7 +138,7 @@ unsafe extern "C" fn artichoke_securerandom_hex(
     let len = mrb_get_args!(mrb, optional = 1);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
-    let len = len.map(Value::from).and_then(|len| guard.convert(len));
+    let len = Value::from_optional_arg(len);
     let result = trampoline::hex(&mut guard, len);
     match result {
         Ok(value) => value.inner(),
@@ -97,6 +146,23 @@ unsafe extern "C" fn artichoke_securerandom_hex(
     }
 }
 
+#[no_mangle]
+unsafe extern "C" fn artichoke_securerandom_urlsafe_base64(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let (len, padding) = mrb_get_args!(mrb, optional = 2);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let len = Value::from_optional_arg(len);
+    let padding = Value::from_optional_arg(padding);
+    let result = trampoline::urlsafe_base64(&mut guard, len, padding);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
 #[no_mangle]
 unsafe extern "C" fn artichoke_securerandom_random_bytes(
     mrb: *mut sys::mrb_state,
@@ -105,7 +171,7 @@ unsafe extern "C" fn artichoke_securerandom_random_bytes(
     let len = mrb_get_args!(mrb, optional = 1);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
-    let len = len.map(Value::from).and_then(|len| guard.convert(len));
+    let len = Value::from_optional_arg(len);
     let result = trampoline::random_bytes(&mut guard, len);
     match result {
         Ok(value) => value.inner(),
@@ -121,7 +187,7 @@ unsafe extern "C" fn artichoke_securerandom_random_number(
     let max = mrb_get_args!(mrb, optional = 1);
     let mut interp = unwrap_interpreter!(mrb);
     let mut guard = Guard::new(&mut interp);
-    let max = max.map(Value::from).and_then(|max| guard.convert(max));
+    let max = Value::from_optional_arg(max);
     let result = trampoline::random_number(&mut guard, max);
     match result {
         Ok(value) => value.inner(),
@@ -143,3 +209,63 @@ unsafe extern "C" fn artichoke_securerandom_uuid(
         Err(exception) => exception::raise(guard, exception),
     }
 }
+
+#[no_mangle]
+unsafe extern "C" fn artichoke_securerandom_uuid_v7(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let result = trampoline::uuid_v7(&mut guard);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => exception::raise(guard, exception),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn hex_edge_cases() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp.eval(b"require 'securerandom'").unwrap();
+        let result = interp.eval(b"SecureRandom.hex(0)").unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("", result);
+        let err = interp.eval(b"SecureRandom.hex(-1)").unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+        // Float lengths are coerced with `#to_int` like MRI.
+        let result = interp.eval(b"SecureRandom.hex(2.5)").unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!(4, result.len());
+        assert!(result.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn uuid_v7_is_hyphenated_and_time_ordered() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp.eval(b"require 'securerandom'").unwrap();
+        let result = interp.eval(b"SecureRandom.uuid_v7").unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!(36, result.len());
+        assert_eq!(Some("7"), result.get(14..15));
+    }
+
+    #[test]
+    fn wrong_typed_optional_length_is_a_type_error() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp.eval(b"require 'securerandom'").unwrap();
+        let err = interp.eval(b"SecureRandom.hex('20')").unwrap_err();
+        assert_eq!("TypeError", err.name().as_ref());
+        let err = interp.eval(b"SecureRandom.random_bytes([])").unwrap_err();
+        assert_eq!("TypeError", err.name().as_ref());
+        // An explicit `nil` is treated like an absent argument.
+        let result = interp.eval(b"SecureRandom.hex(nil)").unwrap();
+        let result = result.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!(32, result.len());
+    }
+}
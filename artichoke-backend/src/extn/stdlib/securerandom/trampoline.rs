@@ -1,47 +1,65 @@
 use crate::extn::prelude::*;
 use crate::extn::stdlib::securerandom;
 
-#[inline]
-pub fn alphanumeric(interp: &mut Artichoke, len: Option<Value>) -> Result<Value, Exception> {
-    let alpha = if let Some(len) = len {
+/// Extract an optional length argument with a `None` default.
+///
+/// Present arguments are coerced with `#to_int`, so a wrong-typed length
+/// surfaces as a `TypeError` instead of being silently dropped.
+fn optional_len(interp: &mut Artichoke, len: Option<Value>) -> Result<Option<Int>, Exception> {
+    if let Some(len) = len {
         let len = len.implicitly_convert_to_int(interp)?;
-        securerandom::alphanumeric(Some(len))?
+        Ok(Some(len))
     } else {
-        securerandom::alphanumeric(None)?
-    };
+        Ok(None)
+    }
+}
+
+#[inline]
+pub fn alphanumeric(interp: &mut Artichoke, len: Option<Value>) -> Result<Value, Exception> {
+    let len = optional_len(interp, len)?;
+    let alpha = securerandom::alphanumeric(len)?;
     Ok(interp.convert_mut(alpha))
 }
 
 #[inline]
 pub fn base64(interp: &mut Artichoke, len: Option<Value>) -> Result<Value, Exception> {
-    let base64 = if let Some(len) = len {
-        let len = len.implicitly_convert_to_int(interp)?;
-        securerandom::base64(Some(len))?
-    } else {
-        securerandom::base64(None)?
-    };
+    let len = optional_len(interp, len)?;
+    let base64 = securerandom::base64(interp, len)?;
     Ok(interp.convert_mut(base64))
 }
 
 #[inline]
 pub fn hex(interp: &mut Artichoke, len: Option<Value>) -> Result<Value, Exception> {
-    let hex = if let Some(len) = len {
-        let len = len.implicitly_convert_to_int(interp)?;
-        securerandom::hex(Some(len))?
-    } else {
-        securerandom::hex(None)?
-    };
+    let len = optional_len(interp, len)?;
+    let hex = securerandom::hex(interp, len)?;
     Ok(interp.convert_mut(hex))
 }
 
 #[inline]
-pub fn random_bytes(interp: &mut Artichoke, len: Option<Value>) -> Result<Value, Exception> {
-    let bytes = if let Some(len) = len {
-        let len = len.implicitly_convert_to_int(interp)?;
-        securerandom::random_bytes(Some(len))?
+pub fn urlsafe_base64(
+    interp: &mut Artichoke,
+    len: Option<Value>,
+    padding: Option<Value>,
+) -> Result<Value, Exception> {
+    let len = optional_len(interp, len)?;
+    // Padding is enabled by any truthy second argument, matching MRI's
+    // `urlsafe_base64(n = nil, padding = false)` signature.
+    let padding = if let Some(padding) = padding {
+        padding
+            .try_into::<Option<bool>>(interp)
+            .unwrap_or(Some(true))
+            .unwrap_or_default()
     } else {
-        securerandom::random_bytes(None)?
+        false
     };
+    let base64 = securerandom::urlsafe_base64(interp, len, padding)?;
+    Ok(interp.convert_mut(base64))
+}
+
+#[inline]
+pub fn random_bytes(interp: &mut Artichoke, len: Option<Value>) -> Result<Value, Exception> {
+    let len = optional_len(interp, len)?;
+    let bytes = securerandom::random_bytes(interp, len)?;
     Ok(interp.convert_mut(bytes))
 }
 
@@ -52,8 +70,28 @@ pub fn random_number(interp: &mut Artichoke, max: Option<Value>) -> Result<Value
     Ok(interp.convert_mut(num))
 }
 
+#[inline]
+pub fn rand(interp: &mut Artichoke, max: Option<Value>) -> Result<Value, Exception> {
+    random_number(interp, max)
+}
+
+#[inline]
+pub fn choose(interp: &mut Artichoke, source: Value, len: Value) -> Result<Value, Exception> {
+    let len = len.implicitly_convert_to_int(interp)?;
+    // Treat the character source as bytes to stay encoding-agnostic.
+    let source = source.implicitly_convert_to_string(interp)?;
+    let chosen = securerandom::choose(source, len)?;
+    Ok(interp.convert_mut(chosen))
+}
+
 #[inline]
 pub fn uuid(interp: &mut Artichoke) -> Result<Value, Exception> {
-    let uuid = securerandom::uuid();
+    let uuid = securerandom::uuid(interp)?;
+    Ok(interp.convert_mut(uuid))
+}
+
+#[inline]
+pub fn uuid_v7(interp: &mut Artichoke) -> Result<Value, Exception> {
+    let uuid = securerandom::uuid_v7(interp)?;
     Ok(interp.convert_mut(uuid))
 }
@@ -0,0 +1,115 @@
+//! Drive Ruby `Fiber`s from Rust.
+//!
+//! mruby's `Fiber` class from the [`mruby-fiber`] mrbgem supports cooperative
+//! scheduling with `Fiber#resume` and `Fiber.yield`. This module wraps a Ruby
+//! `Fiber` in a Rust handle so embedders integrating with an event loop can
+//! run Ruby code that pauses at fiber boundaries and returns control to Rust
+//! between resumes.
+//!
+//! [`mruby-fiber`]: https://github.com/mruby/mruby/tree/master/mrbgems/mruby-fiber
+
+use crate::core::{Eval, Value as _};
+use crate::exception::Exception;
+use crate::value::Value;
+use crate::Artichoke;
+
+/// A handle to a Ruby `Fiber` that can be resumed from Rust.
+///
+/// The wrapped fiber retains its execution state between calls to
+/// [`resume`](Self::resume), which makes it suitable for cooperative,
+/// `eval_async`-style scheduling on top of a synchronous interpreter.
+#[derive(Debug, Clone, Copy)]
+pub struct Fiber(Value);
+
+impl Fiber {
+    /// Wrap Ruby code in a new `Fiber`.
+    ///
+    /// The code is not run until the fiber is [resumed](Self::resume). Each
+    /// `Fiber.yield` in the code suspends the fiber and returns the yielded
+    /// value to the resuming caller.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter fails to construct the fiber, for example because
+    /// the given code has a syntax error, an error is returned.
+    pub fn from_source(interp: &mut Artichoke, code: &[u8]) -> Result<Self, Exception> {
+        let mut source = b"Fiber.new do\n".to_vec();
+        source.extend_from_slice(code);
+        source.extend_from_slice(b"\nend");
+        let fiber = interp.eval(source.as_slice())?;
+        Ok(Self(fiber))
+    }
+
+    /// Wrap an existing Ruby `Fiber` value.
+    #[must_use]
+    pub fn from_value(fiber: Value) -> Self {
+        Self(fiber)
+    }
+
+    /// Resume the fiber until it yields or completes.
+    ///
+    /// Returns the value passed to `Fiber.yield`, or the value of the final
+    /// expression once the fiber runs to completion. Use
+    /// [`is_alive`](Self::is_alive) to distinguish the two.
+    ///
+    /// # Errors
+    ///
+    /// If the fiber is dead or the resumed code raises, the exception is
+    /// returned.
+    pub fn resume(&self, interp: &mut Artichoke, args: &[Value]) -> Result<Value, Exception> {
+        self.0.funcall(interp, "resume", args, None)
+    }
+
+    /// Whether the fiber can be resumed again.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying call to `Fiber#alive?` raises, the exception is
+    /// returned.
+    pub fn is_alive(&self, interp: &mut Artichoke) -> Result<bool, Exception> {
+        let alive = self.0.funcall(interp, "alive?", &[], None)?;
+        alive.try_into::<bool>(interp)
+    }
+
+    /// Consume the handle and return the underlying `Fiber` [`Value`].
+    #[must_use]
+    pub fn into_value(self) -> Value {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fiber;
+    use crate::test::prelude::*;
+
+    #[test]
+    fn collect_yielded_values() {
+        let mut interp = crate::interpreter().unwrap();
+        let fiber = Fiber::from_source(
+            &mut interp,
+            b"Fiber.yield 1\nFiber.yield 2\n3",
+        )
+        .unwrap();
+        let mut values = Vec::new();
+        loop {
+            let value = fiber.resume(&mut interp, &[]).unwrap();
+            values.push(value.try_into::<Int>(&interp).unwrap());
+            if !fiber.is_alive(&mut interp).unwrap() {
+                break;
+            }
+        }
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn resume_dead_fiber() {
+        let mut interp = crate::interpreter().unwrap();
+        let fiber = Fiber::from_source(&mut interp, b"9").unwrap();
+        let value = fiber.resume(&mut interp, &[]).unwrap();
+        assert_eq!(value.try_into::<Int>(&interp).unwrap(), 9);
+        assert!(!fiber.is_alive(&mut interp).unwrap());
+        let err = fiber.resume(&mut interp, &[]).unwrap_err();
+        assert_eq!("FiberError", err.name().as_ref());
+    }
+}
@@ -12,6 +12,7 @@
 //! support reading from the system fs.
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt;
 use std::io;
 use std::path::{Component, Path, PathBuf};
@@ -131,6 +132,54 @@ impl Default for Box<dyn Filesystem> {
     }
 }
 
+/// Embedder-injectable source resolution for `Kernel#require` and friends.
+///
+/// Embedders that bundle Ruby sources in memory or in an archive can install a
+/// resolver with
+/// [`Artichoke::set_load_path_resolver`](crate::Artichoke::set_load_path_resolver).
+/// `require`, `require_relative`, and `load` consult the resolver for each
+/// candidate path before falling back to the virtual filesystem. Resolved
+/// sources are defined into the virtual filesystem, so require-once tracking
+/// applies to them like any other source.
+pub trait LoadPathResolver: fmt::Debug {
+    /// Return the Ruby source for `path`, if this resolver serves it.
+    ///
+    /// `path` is a fully resolved candidate path, for example
+    /// `/src/lib/foo.rb`. Returning `None` falls through to the next
+    /// resolution mechanism.
+    fn resolve(&self, path: &str) -> Option<Vec<u8>>;
+}
+
+/// A [`LoadPathResolver`] backed by an in-memory map from path to source.
+#[derive(Default, Debug, Clone)]
+pub struct MemoryResolver {
+    sources: HashMap<String, Vec<u8>>,
+}
+
+impl MemoryResolver {
+    /// Construct a new, empty `MemoryResolver`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register Ruby source contents at `path`.
+    ///
+    /// Any contents previously registered at `path` are replaced.
+    pub fn insert<T>(&mut self, path: &str, contents: T)
+    where
+        T: Into<Vec<u8>>,
+    {
+        self.sources.insert(String::from(path), contents.into());
+    }
+}
+
+impl LoadPathResolver for MemoryResolver {
+    fn resolve(&self, path: &str) -> Option<Vec<u8>> {
+        self.sources.get(path).cloned()
+    }
+}
+
 fn absolutize_relative_to<T, U>(path: T, cwd: U) -> PathBuf
 where
     T: AsRef<Path>,
@@ -191,3 +191,60 @@ impl From<Box<SymbolOverflowError>> for Box<dyn RubyException> {
         exception
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn lookup_symbol_round_trips_interned_bytes() {
+        let mut interp = crate::interpreter().unwrap();
+        let sym = interp.intern_bytes(&b"artichoke"[..]).unwrap();
+        let bytes = interp.lookup_symbol(sym).unwrap();
+        assert_eq!(Some(&b"artichoke"[..]), bytes);
+    }
+
+    #[test]
+    fn lookup_symbol_strips_trailing_nul() {
+        let mut interp = crate::interpreter().unwrap();
+        let sym = interp.intern_bytes(&b"globe\0"[..]).unwrap();
+        assert_eq!(Some(&b"globe"[..]), interp.lookup_symbol(sym).unwrap());
+        assert_eq!(
+            Some(&b"globe\0"[..]),
+            interp.lookup_symbol_with_trailing_nul(sym).unwrap()
+        );
+    }
+
+    #[test]
+    fn intern_all_returns_symbols_in_input_order() {
+        let mut interp = crate::interpreter().unwrap();
+        let names = vec![
+            b"each".to_vec(),
+            b"map".to_vec(),
+            b"select".to_vec(),
+            b"each".to_vec(),
+        ];
+        let symbols = interp.intern_all(names).unwrap();
+        assert_eq!(4, symbols.len());
+        assert_eq!(Some(&b"each"[..]), interp.lookup_symbol(symbols[0]).unwrap());
+        assert_eq!(Some(&b"map"[..]), interp.lookup_symbol(symbols[1]).unwrap());
+        assert_eq!(
+            Some(&b"select"[..]),
+            interp.lookup_symbol(symbols[2]).unwrap()
+        );
+        // Duplicate names resolve to the same identifier.
+        assert_eq!(symbols[0], symbols[3]);
+        assert_eq!(
+            Some(symbols[0]),
+            interp.check_interned_bytes(b"each").unwrap()
+        );
+    }
+
+    #[test]
+    fn lookup_symbol_returns_none_for_unknown_ids() {
+        let interp = crate::interpreter().unwrap();
+        // Symbol id zero is reserved; mruby symbols are non-zero.
+        assert_eq!(None, interp.lookup_symbol(0_u32.into()).unwrap());
+        assert_eq!(None, interp.lookup_symbol(u32::max_value().into()).unwrap());
+    }
+}
@@ -31,6 +31,130 @@ pub fn interpreter() -> Result<Artichoke, Exception> {
 /// [`interpreter`] function.
 #[allow(clippy::module_name_repetitions)]
 pub fn interpreter_with_config<T>(config: T) -> Result<Artichoke, Exception>
+where
+    T: ReleaseMetadata,
+{
+    boot(config, extn::stdlib::Config::default())
+}
+
+/// Builder for [`Artichoke`] interpreters with opt-in stdlib packages.
+///
+/// Booting the full standard library costs startup time an embedder may not
+/// need. Each `with_*` toggle controls whether the corresponding stdlib
+/// package is registered during boot; disabled packages cannot be `require`d
+/// on the resulting interpreter. All packages are enabled by default, which
+/// matches [`interpreter`].
+#[derive(Default, Debug, Clone, Copy)]
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub struct InterpreterBuilder {
+    stdlib: extn::stdlib::Config,
+}
+
+impl InterpreterBuilder {
+    /// Construct a new builder with all stdlib packages enabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Toggle registration of the `abbrev` package.
+    pub fn with_abbrev(mut self, enabled: bool) -> Self {
+        self.stdlib.abbrev = enabled;
+        self
+    }
+
+    /// Toggle registration of the `base64` package.
+    pub fn with_base64(mut self, enabled: bool) -> Self {
+        self.stdlib.base64 = enabled;
+        self
+    }
+
+    /// Toggle registration of the `cmath` package.
+    pub fn with_cmath(mut self, enabled: bool) -> Self {
+        self.stdlib.cmath = enabled;
+        self
+    }
+
+    /// Toggle registration of the `delegate` package.
+    pub fn with_delegate(mut self, enabled: bool) -> Self {
+        self.stdlib.delegate = enabled;
+        self
+    }
+
+    /// Toggle registration of the `forwardable` package.
+    pub fn with_forwardable(mut self, enabled: bool) -> Self {
+        self.stdlib.forwardable = enabled;
+        self
+    }
+
+    /// Toggle registration of the `json` package.
+    pub fn with_json(mut self, enabled: bool) -> Self {
+        self.stdlib.json = enabled;
+        self
+    }
+
+    /// Toggle registration of the `monitor` package.
+    pub fn with_monitor(mut self, enabled: bool) -> Self {
+        self.stdlib.monitor = enabled;
+        self
+    }
+
+    /// Toggle registration of the `ostruct` package.
+    pub fn with_ostruct(mut self, enabled: bool) -> Self {
+        self.stdlib.ostruct = enabled;
+        self
+    }
+
+    /// Toggle registration of the `securerandom` package.
+    ///
+    /// This toggle has no effect unless the `stdlib-securerandom` feature is
+    /// enabled.
+    pub fn with_securerandom(mut self, enabled: bool) -> Self {
+        self.stdlib.securerandom = enabled;
+        self
+    }
+
+    /// Toggle registration of the `set` package.
+    pub fn with_set(mut self, enabled: bool) -> Self {
+        self.stdlib.set = enabled;
+        self
+    }
+
+    /// Toggle registration of the `shellwords` package.
+    pub fn with_shellwords(mut self, enabled: bool) -> Self {
+        self.stdlib.shellwords = enabled;
+        self
+    }
+
+    /// Toggle registration of the `strscan` package.
+    pub fn with_strscan(mut self, enabled: bool) -> Self {
+        self.stdlib.strscan = enabled;
+        self
+    }
+
+    /// Toggle registration of the `time` package.
+    pub fn with_time(mut self, enabled: bool) -> Self {
+        self.stdlib.time = enabled;
+        self
+    }
+
+    /// Toggle registration of the `uri` package.
+    pub fn with_uri(mut self, enabled: bool) -> Self {
+        self.stdlib.uri = enabled;
+        self
+    }
+
+    /// Create and initialize an [`Artichoke`] interpreter from this builder.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter fails to allocate or boot, an error is returned.
+    pub fn build(self) -> Result<Artichoke, Exception> {
+        boot(ArtichokeBackendReleaseMetadata::default(), self.stdlib)
+    }
+}
+
+fn boot<T>(config: T, stdlib_config: extn::stdlib::Config) -> Result<Artichoke, Exception>
 where
     T: ReleaseMetadata,
 {
@@ -55,7 +179,7 @@ where
 
     // Initialize Artichoke Core and Standard Library runtime
     debug!("Begin initializing Artichoke Core and Standard Library");
-    extn::init(&mut interp, config)?;
+    extn::init_with_stdlib(&mut interp, config, stdlib_config)?;
     debug!("Succeeded initializing Artichoke Core and Standard Library");
 
     // Load mrbgems
@@ -152,9 +276,35 @@ impl From<Box<InterpreterAllocError>> for Box<dyn RubyException> {
 
 #[cfg(test)]
 mod tests {
+    use crate::test::prelude::*;
+
     #[test]
     fn open_close() {
         let interp = super::interpreter().unwrap();
         interp.close();
     }
+
+    #[cfg(feature = "stdlib-securerandom")]
+    #[test]
+    fn builder_disables_stdlib_packages() {
+        let mut interp = super::InterpreterBuilder::new()
+            .with_securerandom(false)
+            .build()
+            .unwrap();
+        let err = interp.eval(b"require 'securerandom'").unwrap_err();
+        assert_eq!("LoadError", err.name().as_ref());
+        // Core functionality is unaffected.
+        let result = interp.eval(b"2 + 2").unwrap();
+        assert_eq!(4, result.try_into::<Int>(&interp).unwrap());
+    }
+
+    #[cfg(feature = "stdlib-securerandom")]
+    #[test]
+    fn builder_enables_all_stdlib_packages_by_default() {
+        let mut interp = super::InterpreterBuilder::new().build().unwrap();
+        let result = interp
+            .eval(b"require 'securerandom'; defined?(SecureRandom)")
+            .unwrap();
+        assert!(!result.is_nil());
+    }
 }
@@ -17,29 +17,44 @@ impl Io for Artichoke {
 
     /// Writes the given bytes to the interpreter stdout stream.
     ///
-    /// This implementation delegates to the underlying output strategy.
+    /// This implementation delegates to the underlying output strategy. An
+    /// active capture installed by
+    /// [`Artichoke::eval_capturing_output`](crate::Artichoke::eval_capturing_output)
+    /// takes precedence over the strategy.
     ///
     /// # Errors
     ///
     /// If the output stream encounters an error, an error is returned.
     fn print<T: AsRef<[u8]>>(&mut self, message: T) -> Result<(), Self::Error> {
         let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
-        state.output.write_stdout(message.as_ref())?;
+        if let Some(ref mut capture) = state.capture {
+            capture.write_stdout(message.as_ref())?;
+        } else {
+            state.output.write_stdout(message.as_ref())?;
+        }
         Ok(())
     }
 
     /// Writes the given bytes to the interpreter stdout stream followed by a
     /// newline.
     ///
-    /// This implementation delegates to the underlying output strategy.
+    /// This implementation delegates to the underlying output strategy. An
+    /// active capture installed by
+    /// [`Artichoke::eval_capturing_output`](crate::Artichoke::eval_capturing_output)
+    /// takes precedence over the strategy.
     ///
     /// # Errors
     ///
     /// If the output stream encounters an error, an error is returned.
     fn puts<T: AsRef<[u8]>>(&mut self, message: T) -> Result<(), Self::Error> {
         let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
-        state.output.write_stdout(message.as_ref())?;
-        state.output.write_stdout(b"\n")?;
+        if let Some(ref mut capture) = state.capture {
+            capture.write_stdout(message.as_ref())?;
+            capture.write_stdout(b"\n")?;
+        } else {
+            state.output.write_stdout(message.as_ref())?;
+            state.output.write_stdout(b"\n")?;
+        }
         Ok(())
     }
 }
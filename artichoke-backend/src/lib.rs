@@ -122,6 +122,7 @@ pub mod exception;
 pub mod exception_handler;
 pub mod extn;
 pub mod ffi;
+pub mod fiber;
 pub mod fs;
 pub mod gc;
 mod globals;
@@ -148,9 +149,9 @@ mod warn;
 #[cfg(test)]
 mod test;
 
-pub use crate::artichoke::{Artichoke, Guard};
-pub use crate::exception::{Exception, RubyException};
-pub use crate::interpreter::{interpreter, interpreter_with_config};
+pub use crate::artichoke::{Artichoke, Guard, SendableArtichoke};
+pub use crate::exception::{BacktraceOrder, Exception, RubyException};
+pub use crate::interpreter::{interpreter, interpreter_with_config, InterpreterBuilder};
 pub use artichoke_core::prelude as core;
 
 /// A "prelude" for users of the `artichoke-backend` crate.
@@ -167,10 +168,10 @@ pub use artichoke_core::prelude as core;
 pub mod prelude {
     pub use crate::core;
 
-    pub use crate::exception::{raise, Exception, RubyException};
+    pub use crate::exception::{raise, BacktraceOrder, Exception, RubyException};
     pub use crate::extn::core::exception::{Exception as _, *};
     pub use crate::gc::MrbGarbageCollection;
-    pub use crate::interpreter::{interpreter, interpreter_with_config};
+    pub use crate::interpreter::{interpreter, interpreter_with_config, InterpreterBuilder};
     pub use crate::release_metadata::ReleaseMetadata;
     pub use crate::{Artichoke, Guard};
 }
@@ -1,12 +1,123 @@
 use std::borrow::Cow;
 use std::path::Path;
 
-use crate::core::{Eval, File, LoadSources};
+use crate::core::{Eval, File, Globals, LoadSources, TryConvertMut};
 use crate::exception::Exception;
-use crate::ffi::InterpreterExtractError;
+use crate::ffi::{self, InterpreterExtractError};
 use crate::fs::RUBY_LOAD_PATH;
+use crate::value::Value;
 use crate::Artichoke;
 
+impl Artichoke {
+    /// Add a directory to the front of the interpreter load path.
+    ///
+    /// Subsequent calls to `Kernel#require` resolve relative paths against the
+    /// given directory before any other load path entry. Directories already
+    /// present in `$LOAD_PATH` are not added twice.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter cannot read or update the `$LOAD_PATH` global, an
+    /// error is returned.
+    pub fn prepend_load_path(&mut self, dir: &[u8]) -> Result<(), Exception> {
+        self.modify_load_path(dir, true)
+    }
+
+    /// Add a directory to the back of the interpreter load path.
+    ///
+    /// Subsequent calls to `Kernel#require` resolve relative paths against the
+    /// given directory after all other load path entries. Directories already
+    /// present in `$LOAD_PATH` are not added twice.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter cannot read or update the `$LOAD_PATH` global, an
+    /// error is returned.
+    pub fn append_load_path(&mut self, dir: &[u8]) -> Result<(), Exception> {
+        self.modify_load_path(dir, false)
+    }
+
+    fn modify_load_path(&mut self, dir: &[u8], prepend: bool) -> Result<(), Exception> {
+        let mut entries: Vec<Vec<u8>> =
+            if let Some(load_path) = self.get_global_variable(&b"$LOAD_PATH"[..])? {
+                self.try_convert_mut(load_path).unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+        if entries.is_empty() {
+            // Seed the load path with the default source directory so adding
+            // directories never masks sources shipped in the default load
+            // path.
+            entries.push(RUBY_LOAD_PATH.as_bytes().to_vec());
+        }
+        if entries.iter().any(|entry| entry.as_slice() == dir) {
+            return Ok(());
+        }
+        if prepend {
+            entries.insert(0, dir.to_vec());
+        } else {
+            entries.push(dir.to_vec());
+        }
+        let load_path: Value = self.try_convert_mut(entries)?;
+        // `$LOAD_PATH` and `$:` are aliases in MRI; keep both globals in sync.
+        self.set_global_variable(&b"$LOAD_PATH"[..], &load_path)?;
+        self.set_global_variable(&b"$:"[..], &load_path)?;
+        Ok(())
+    }
+
+    /// Enumerate the features `Kernel#require` has loaded, in load order.
+    ///
+    /// This is the Rust-side view of the set `require` consults for its
+    /// load-once guarantee, mirrored into the `$LOADED_FEATURES` global.
+    /// Paths executed with `Kernel#load` are not recorded, matching MRI,
+    /// where `load` always re-executes.
+    pub fn loaded_features(&self) -> impl Iterator<Item = &[u8]> {
+        self.state
+            .as_ref()
+            .map(|state| state.loaded_features.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(Vec::as_slice)
+    }
+
+    /// Mark `path` as already loaded so subsequent `require`s of it return
+    /// `false` without executing the source.
+    ///
+    /// `path` must be the fully resolved form `require` probes, for example
+    /// `/src/lib/feature.rb`. This is useful for sandbox reuse, where an
+    /// embedder pre-loads shared sources once and hands the interpreter to
+    /// untrusted code afterwards. Paths already recorded are not added twice.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter cannot update the `$LOADED_FEATURES` global, an
+    /// error is returned.
+    pub fn mark_feature_loaded(&mut self, path: &[u8]) -> Result<(), Exception> {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        if state
+            .loaded_features
+            .iter()
+            .any(|feature| feature.as_slice() == path)
+        {
+            return Ok(());
+        }
+        state.loaded_features.push(path.to_vec());
+        self.sync_loaded_features()
+    }
+
+    /// Mirror the Rust-side loaded feature set into the `$LOADED_FEATURES`
+    /// and `$"` globals.
+    fn sync_loaded_features(&mut self) -> Result<(), Exception> {
+        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+        let features = state.loaded_features.clone();
+        let features: Value = self.try_convert_mut(features)?;
+        // `$LOADED_FEATURES` and `$"` are aliases in MRI; keep both in sync.
+        self.set_global_variable(&b"$LOADED_FEATURES"[..], &features)?;
+        self.set_global_variable(&b"$\""[..], &features)?;
+        Ok(())
+    }
+}
+
 impl LoadSources for Artichoke {
     type Artichoke = Self;
     type Error = Exception;
@@ -87,10 +198,17 @@ impl LoadSources for Artichoke {
     where
         P: AsRef<Path>,
     {
+        let path_bytes = ffi::os_str_to_bytes(path.as_ref().as_os_str())?.to_vec();
         {
             let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
-            // If a file is already required, short circuit.
-            if state.vfs.is_required(path.as_ref()) {
+            // If a file is already required, short circuit. Features recorded
+            // with `Artichoke::mark_feature_loaded` count as required.
+            if state.vfs.is_required(path.as_ref())
+                || state
+                    .loaded_features
+                    .iter()
+                    .any(|feature| *feature == path_bytes)
+            {
                 return Ok(false);
             }
             // Require Rust `File` first because an File may define classes and
@@ -107,6 +225,8 @@ impl LoadSources for Artichoke {
         self.eval(contents.as_ref())?;
         let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
         state.vfs.mark_required(path.as_ref())?;
+        state.loaded_features.push(path_bytes);
+        self.sync_loaded_features()?;
         trace!(r#"Successful require of {}"#, path.as_ref().display());
         Ok(true)
     }
@@ -120,3 +240,66 @@ impl LoadSources for Artichoke {
         Ok(contents.to_vec().into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    // TODO(GH-528): fix failing tests on Windows.
+    #[cfg_attr(target_os = "windows", should_panic)]
+    fn require_records_loaded_features() {
+        let mut interp = crate::interpreter().unwrap();
+        interp
+            .def_rb_source_file("feature.rb", &b"# a feature"[..])
+            .unwrap();
+        assert!(!interp
+            .loaded_features()
+            .any(|feature| feature == &b"/src/lib/feature.rb"[..]));
+        let result = interp.eval(b"require 'feature'").unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        assert!(interp
+            .loaded_features()
+            .any(|feature| feature == &b"/src/lib/feature.rb"[..]));
+        // The `$LOADED_FEATURES` global mirrors the Rust-side set.
+        let result = interp
+            .eval(b"$LOADED_FEATURES.include?('/src/lib/feature.rb')")
+            .unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        // The load-once guarantee consults the same set.
+        let result = interp.eval(b"require 'feature'").unwrap();
+        assert!(!result.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn load_does_not_record_loaded_features() {
+        let mut interp = crate::interpreter().unwrap();
+        interp
+            .def_rb_source_file("feature.rb", &b"$loads = ($loads || 0) + 1"[..])
+            .unwrap();
+        let result = interp.eval(b"load 'feature.rb'").unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+        assert!(interp.loaded_features().next().is_none());
+        // `load` always re-executes and does not mark the feature required.
+        let result = interp.eval(b"load 'feature.rb'; $loads").unwrap();
+        assert_eq!(2, result.try_into::<Int>(&interp).unwrap());
+        let result = interp.eval(b"require 'feature'; $loads").unwrap();
+        assert_eq!(3, result.try_into::<Int>(&interp).unwrap());
+    }
+
+    #[test]
+    // TODO(GH-528): fix failing tests on Windows.
+    #[cfg_attr(target_os = "windows", should_panic)]
+    fn mark_feature_loaded_short_circuits_require() {
+        let mut interp = crate::interpreter().unwrap();
+        interp
+            .def_rb_source_file("feature.rb", &b"$loaded = true"[..])
+            .unwrap();
+        interp.mark_feature_loaded(b"/src/lib/feature.rb").unwrap();
+        let result = interp.eval(b"require 'feature'").unwrap();
+        assert!(!result.try_into::<bool>(&interp).unwrap());
+        // The source never executed.
+        let result = interp.eval(b"$loaded").unwrap();
+        assert!(result.is_nil());
+    }
+}
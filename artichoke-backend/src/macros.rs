@@ -35,11 +35,14 @@ pub mod argspec {
     pub const NONE: &[u8] = b"\0";
     pub const REQ1: &[u8] = b"o\0";
     pub const OPT1: &[u8] = b"|o\0";
+    pub const OPT2: &[u8] = b"|oo\0";
     pub const REQ1_OPT1: &[u8] = b"o|o\0";
     pub const REQ1_OPT2: &[u8] = b"o|oo\0";
     pub const REQ1_REQBLOCK: &[u8] = b"o&\0";
     pub const REQ1_REQBLOCK_OPT1: &[u8] = b"o&|o?\0";
     pub const REQ2: &[u8] = b"oo\0";
+    pub const OPTBLOCK: &[u8] = b"&\0";
+    pub const OPT1_OPTBLOCK: &[u8] = b"&|o?\0";
     pub const OPT2_OPTBLOCK: &[u8] = b"&|o?o?\0";
     pub const REQ2_OPT1: &[u8] = b"oo|o\0";
     pub const REST: &[u8] = b"*\0";
@@ -86,6 +89,29 @@ macro_rules! mrb_get_args {
             _ => unreachable!("mrb_get_args should have raised"),
         }
     }};
+    ($mrb:expr, optional = 2) => {{
+        let mut opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        let mut opt2 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        let argc = $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::OPT2.as_ptr() as *const i8,
+            opt1.as_mut_ptr(),
+            opt2.as_mut_ptr(),
+        );
+        match argc {
+            2 => {
+                let opt1 = opt1.assume_init();
+                let opt2 = opt2.assume_init();
+                (Some(opt1), Some(opt2))
+            }
+            1 => {
+                let opt1 = opt1.assume_init();
+                (Some(opt1), None)
+            }
+            0 => (None, None),
+            _ => unreachable!("mrb_get_args should have raised"),
+        }
+    }};
     ($mrb:expr, required = 1, optional = 1) => {{
         let mut req1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
         let mut opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
@@ -213,6 +239,36 @@ macro_rules! mrb_get_args {
             _ => unreachable!("mrb_get_args should have raised"),
         }
     }};
+    ($mrb:expr, &block) => {{
+        let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::OPTBLOCK.as_ptr() as *const i8,
+            block.as_mut_ptr(),
+        );
+        let block = block.assume_init();
+        $crate::block::Block::new(block)
+    }};
+    ($mrb:expr, optional = 1, &block) => {{
+        let mut opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        let mut has_opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_bool>::uninit();
+        let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::OPT1_OPTBLOCK.as_ptr() as *const i8,
+            block.as_mut_ptr(),
+            opt1.as_mut_ptr(),
+            has_opt1.as_mut_ptr(),
+        );
+        let has_opt1 = has_opt1.assume_init() != 0;
+        let opt1 = if has_opt1 {
+            Some(opt1.assume_init())
+        } else {
+            None
+        };
+        let block = block.assume_init();
+        (opt1, $crate::block::Block::new(block))
+    }};
     ($mrb:expr, optional = 2, &block) => {{
         let mut opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
         let mut has_opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_bool>::uninit();
@@ -1,11 +1,16 @@
 use std::borrow::Cow;
-use std::ffi::{CStr, CString};
+use std::ffi::{c_void, CStr, CString};
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::mem;
 
+use crate::core::{Intern, Value as _};
 use crate::def::{ConstantNameError, Method, NotDefinedError};
+use crate::exception;
+use crate::extn::core::exception::NoMethodError;
 use crate::sys;
-use crate::Artichoke;
+use crate::value::Value;
+use crate::{Artichoke, Guard};
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Type {
@@ -15,6 +20,27 @@ pub enum Type {
     Module,
 }
 
+/// Ruby method visibility.
+///
+/// Native method definitions default to [`Visibility::Public`]. mruby's method
+/// table does not record visibility — `Module#private` and `Module#protected`
+/// are dummy modifiers in the VM — so private and protected methods are
+/// defined through a wrapper proc that checks the calling frame on every
+/// dispatch and raises `NoMethodError` when the caller is not allowed to see
+/// the method.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Visibility {
+    Public,
+    Private,
+    Protected,
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Self::Public
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Spec {
     name: Cow<'static, str>,
@@ -22,6 +48,7 @@ pub struct Spec {
     method_type: Type,
     method: Method,
     args: sys::mrb_aspec,
+    visibility: Visibility,
 }
 
 impl Spec {
@@ -31,6 +58,19 @@ impl Spec {
         method: Method,
         args: sys::mrb_aspec,
     ) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Self::with_visibility(method_type, method_name, method, args, Visibility::Public)
+    }
+
+    pub fn with_visibility<T>(
+        method_type: Type,
+        method_name: T,
+        method: Method,
+        args: sys::mrb_aspec,
+        visibility: Visibility,
+    ) -> Result<Self, ConstantNameError>
     where
         T: Into<Cow<'static, str>>,
     {
@@ -42,6 +82,7 @@ impl Spec {
                 method_type,
                 method,
                 args,
+                visibility,
             })
         } else {
             Err(name.into())
@@ -53,6 +94,11 @@ impl Spec {
         &self.method_type
     }
 
+    #[must_use]
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
     #[must_use]
     pub fn method(&self) -> Method {
         self.method
@@ -85,38 +131,126 @@ impl Spec {
         interp: &mut Artichoke,
         into: &mut sys::RClass,
     ) -> Result<(), NotDefinedError> {
-        interp
-            .with_ffi_boundary(|mrb| match self.method_type {
-                Type::Class => sys::mrb_define_class_method(
-                    mrb,
-                    into,
-                    self.name_c_str().as_ptr(),
-                    Some(self.method),
-                    self.args,
-                ),
-                Type::Global => sys::mrb_define_singleton_method(
-                    mrb,
-                    (*mrb).top_self,
-                    self.name_c_str().as_ptr(),
-                    Some(self.method),
-                    self.args,
-                ),
-                Type::Instance => sys::mrb_define_method(
-                    mrb,
-                    into,
-                    self.name_c_str().as_ptr(),
-                    Some(self.method),
-                    self.args,
-                ),
-                Type::Module => sys::mrb_define_module_function(
-                    mrb,
-                    into,
-                    self.name_c_str().as_ptr(),
-                    Some(self.method),
-                    self.args,
-                ),
-            })
-            .map_err(|_| NotDefinedError::method(self.name()))
+        let visibility = match (self.method_type, self.visibility) {
+            (Type::Instance, Visibility::Private) | (Type::Instance, Visibility::Protected) => {
+                Some(self.visibility)
+            }
+            _ => None,
+        };
+        if let Some(visibility) = visibility {
+            // mruby's method table does not record visibility, so private and
+            // protected methods are defined through a wrapper proc that checks
+            // the calling frame at dispatch time. The method name symbol must
+            // be interned before crossing the FFI boundary because the symbol
+            // table lives in the Artichoke `State`.
+            let method = interp
+                .intern_string(self.name().into_owned())
+                .map_err(|_| NotDefinedError::method(self.name()))?;
+            interp
+                .with_ffi_boundary(|mrb| {
+                    let is_protected = if let Visibility::Protected = visibility {
+                        sys::mrb_sys_true_value()
+                    } else {
+                        sys::mrb_sys_false_value()
+                    };
+                    let name = sys::mrb_str_new_cstr(mrb, self.name_c_str().as_ptr());
+                    let owner = sys::mrb_sys_obj_value((into as *mut sys::RClass).cast::<c_void>());
+                    let env = [
+                        sys::mrb_sys_cptr_value(mrb, self.method as *mut c_void),
+                        is_protected,
+                        name,
+                        owner,
+                    ];
+                    let wrapper = sys::mrb_proc_new_cfunc_with_env(
+                        mrb,
+                        Some(enforce_visibility_trampoline),
+                        4,
+                        env.as_ptr(),
+                    );
+                    sys::mrb_sys_define_method_from_proc(mrb, into, method.into(), wrapper);
+                })
+                .map_err(|_| NotDefinedError::method(self.name()))
+        } else {
+            interp
+                .with_ffi_boundary(|mrb| match self.method_type {
+                    Type::Class => sys::mrb_define_class_method(
+                        mrb,
+                        into,
+                        self.name_c_str().as_ptr(),
+                        Some(self.method),
+                        self.args,
+                    ),
+                    Type::Global => sys::mrb_define_singleton_method(
+                        mrb,
+                        (*mrb).top_self,
+                        self.name_c_str().as_ptr(),
+                        Some(self.method),
+                        self.args,
+                    ),
+                    Type::Instance => sys::mrb_define_method(
+                        mrb,
+                        into,
+                        self.name_c_str().as_ptr(),
+                        Some(self.method),
+                        self.args,
+                    ),
+                    Type::Module => sys::mrb_define_module_function(
+                        mrb,
+                        into,
+                        self.name_c_str().as_ptr(),
+                        Some(self.method),
+                        self.args,
+                    ),
+                })
+                .map_err(|_| NotDefinedError::method(self.name()))
+        }
+    }
+}
+
+/// Dispatch shim for private and protected native methods.
+///
+/// The wrapped method function pointer, a protected flag, the method name,
+/// and the defining class-like are stashed in the wrapper proc environment by
+/// [`Spec::define`]. Private methods may only be called when the receiver is
+/// the caller's `self`, which is the observable effect of MRI's
+/// implicit-receiver rule. Protected methods may only be called when the
+/// caller's `self` is an instance of the defining class-like.
+unsafe extern "C" fn enforce_visibility_trampoline(
+    mrb: *mut sys::mrb_state,
+    slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let method = sys::mrb_proc_cfunc_env_get(mrb, 0);
+    let is_protected = sys::mrb_proc_cfunc_env_get(mrb, 1);
+    let name = sys::mrb_proc_cfunc_env_get(mrb, 2);
+    let owner = sys::mrb_proc_cfunc_env_get(mrb, 3);
+    let caller = sys::mrb_sys_caller_self(mrb);
+    let is_protected = sys::mrb_sys_value_is_true(is_protected);
+    let allowed = if is_protected {
+        sys::mrb_obj_is_kind_of(mrb, caller, sys::mrb_sys_class_to_rclass(owner)) != 0
+    } else {
+        sys::mrb_obj_eq(mrb, caller, slf) != 0
+    };
+    if allowed {
+        let method = mem::transmute::<*mut c_void, Method>(sys::mrb_sys_cptr_ptr(method));
+        return method(mrb, slf);
+    }
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let value = Value::from(slf);
+    let name = Value::from(name);
+    match name.implicitly_convert_to_string(&mut guard) {
+        Ok(name) => {
+            let mut message = if is_protected {
+                b"protected method '".to_vec()
+            } else {
+                b"private method '".to_vec()
+            };
+            message.extend_from_slice(name);
+            message.extend_from_slice(b"' called for ");
+            message.extend_from_slice(value.inspect(&mut guard).as_slice());
+            exception::raise(guard, NoMethodError::from(message))
+        }
+        Err(exception) => exception::raise(guard, exception),
     }
 }
 
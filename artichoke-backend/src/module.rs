@@ -1,10 +1,12 @@
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::collections::HashSet;
 use std::convert::AsRef;
 use std::ffi::{c_void, CStr, CString};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::ptr::NonNull;
+use std::rc::Rc;
 
 use crate::core::Intern;
 use crate::def::{ConstantNameError, EnclosingRubyScope, Method, NotDefinedError};
@@ -12,6 +14,7 @@ use crate::exception::Exception;
 use crate::intern::Symbol;
 use crate::method;
 use crate::sys;
+use crate::value::Value;
 use crate::Artichoke;
 
 mod registry;
@@ -23,6 +26,7 @@ pub struct Builder<'a> {
     interp: &'a mut Artichoke,
     spec: &'a Spec,
     methods: HashSet<method::Spec>,
+    nested_modules: Vec<(Cow<'static, str>, CString)>,
 }
 
 impl<'a> Builder<'a> {
@@ -32,6 +36,28 @@ impl<'a> Builder<'a> {
             interp,
             spec,
             methods: HashSet::default(),
+            nested_modules: Vec::default(),
+        }
+    }
+
+    /// Declare an empty module as a constant nested under the module being
+    /// built.
+    ///
+    /// The nested module is created when [`Builder::define`] is called. If the
+    /// constant already resolves to a module, the declaration is a no-op,
+    /// which makes repeated `define` calls idempotent. This is a shorthand
+    /// for building namespaces like `Net::HTTP` without a full
+    /// `Spec`/`Builder` cycle for each segment.
+    pub fn define_nested_module<T>(mut self, name: T) -> Result<Self, Exception>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let name = name.into();
+        if let Ok(cstring) = CString::new(name.as_ref()) {
+            self.nested_modules.push((name, cstring));
+            Ok(self)
+        } else {
+            Err(ConstantNameError::from(name).into())
         }
     }
 
@@ -49,6 +75,46 @@ impl<'a> Builder<'a> {
         Ok(self)
     }
 
+    pub fn add_private_method<T>(
+        mut self,
+        name: T,
+        method: Method,
+        args: sys::mrb_aspec,
+    ) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let spec = method::Spec::with_visibility(
+            method::Type::Instance,
+            name.into(),
+            method,
+            args,
+            method::Visibility::Private,
+        )?;
+        self.methods.insert(spec);
+        Ok(self)
+    }
+
+    pub fn add_protected_method<T>(
+        mut self,
+        name: T,
+        method: Method,
+        args: sys::mrb_aspec,
+    ) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let spec = method::Spec::with_visibility(
+            method::Type::Instance,
+            name.into(),
+            method,
+            args,
+            method::Visibility::Protected,
+        )?;
+        self.methods.insert(spec);
+        Ok(self)
+    }
+
     pub fn add_self_method<T>(
         mut self,
         name: T,
@@ -117,17 +183,41 @@ impl<'a> Builder<'a> {
                 method.define(self.interp, rclass.as_mut())?;
             }
         }
+        for (name, cstring) in self.nested_modules {
+            // `mrb_define_module_under` returns the existing module if the
+            // constant is already defined, so re-defining is a no-op.
+            let nested = unsafe {
+                self.interp.with_ffi_boundary(|mrb| {
+                    sys::mrb_define_module_under(mrb, rclass.as_mut(), cstring.as_ptr())
+                })
+            };
+            let nested = nested.map_err(|_| NotDefinedError::module(name.clone()))?;
+            if nested.is_null() {
+                return Err(NotDefinedError::module(name));
+            }
+        }
         Ok(())
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Rclass {
     sym: Symbol,
     name: CString,
     enclosing_scope: Option<Box<EnclosingRubyScope>>,
+    cache: Rc<Cell<Option<NonNull<sys::RClass>>>>,
 }
 
+impl PartialEq for Rclass {
+    fn eq(&self, other: &Self) -> bool {
+        self.sym == other.sym
+            && self.name == other.name
+            && self.enclosing_scope == other.enclosing_scope
+    }
+}
+
+impl Eq for Rclass {}
+
 impl Rclass {
     #[must_use]
     pub fn new(
@@ -139,19 +229,46 @@ impl Rclass {
             sym,
             name,
             enclosing_scope,
+            cache: Rc::default(),
+        }
+    }
+
+    #[must_use]
+    pub(crate) fn with_cache(
+        sym: Symbol,
+        name: CString,
+        enclosing_scope: Option<Box<EnclosingRubyScope>>,
+        cache: Rc<Cell<Option<NonNull<sys::RClass>>>>,
+    ) -> Self {
+        Self {
+            sym,
+            name,
+            enclosing_scope,
+            cache,
         }
     }
 
     /// Resolve a type's [`sys::RClass`] using its enclosing scope and name.
     ///
+    /// Successful resolutions are memoized: repeated resolutions of the same
+    /// module return the cached [`NonNull`] pointer without walking the
+    /// enclosing scope or calling constant lookup FFI functions. `RClass`
+    /// pointers for defined constants are stable for the life of the
+    /// interpreter because the constant table roots them in the GC. The cache
+    /// is shared with the [`Spec`] that created this `Rclass`; see
+    /// [`Spec::invalidate_rclass_cache`].
+    ///
     /// # Safety
     ///
     /// This function must be called within an [`Artichoke::with_ffi_boundary`]
     /// closure because the FFI APIs called in this function may require access
     /// to the Artichoke [`State](crate::state::State).
     pub unsafe fn resolve(&self, mrb: *mut sys::mrb_state) -> Option<NonNull<sys::RClass>> {
+        if let Some(rclass) = self.cache.get() {
+            return Some(rclass);
+        }
         let module_name = self.name.as_ptr();
-        if let Some(ref scope) = self.enclosing_scope {
+        let resolved = if let Some(ref scope) = self.enclosing_scope {
             // Short circuit if enclosing scope does not exist.
             let mut scope = scope.rclass(mrb)?;
             let is_defined_under = sys::mrb_const_defined_at(
@@ -183,7 +300,11 @@ impl Rclass {
                 let module = sys::mrb_module_get(mrb, module_name);
                 NonNull::new(module)
             }
+        };
+        if resolved.is_some() {
+            self.cache.set(resolved);
         }
+        resolved
     }
 }
 
@@ -193,6 +314,7 @@ pub struct Spec {
     sym: Symbol,
     cstring: CString,
     enclosing_scope: Option<Box<EnclosingRubyScope>>,
+    resolved: Rc<Cell<Option<NonNull<sys::RClass>>>>,
 }
 
 impl Spec {
@@ -215,6 +337,7 @@ impl Spec {
                 cstring,
                 sym,
                 enclosing_scope: enclosing_scope.map(Box::new),
+                resolved: Rc::default(),
             })
         } else {
             Err(ConstantNameError::from(name).into())
@@ -253,7 +376,49 @@ impl Spec {
 
     #[must_use]
     pub fn rclass(&self) -> Rclass {
-        Rclass::new(self.sym, self.cstring.clone(), self.enclosing_scope.clone())
+        Rclass::with_cache(
+            self.sym,
+            self.cstring.clone(),
+            self.enclosing_scope.clone(),
+            Rc::clone(&self.resolved),
+        )
+    }
+
+    /// Resolve this spec's module constant to a live module `Value`.
+    ///
+    /// This folds the common pattern of calling [`Spec::rclass`] and
+    /// [`Rclass::resolve`] inside an [`Artichoke::with_ffi_boundary`] into a
+    /// single call. It mirrors
+    /// [`ModuleRegistry::module_of`](crate::module_registry::ModuleRegistry),
+    /// but is keyed by `Spec` rather than by Rust type. Returns `None` if the
+    /// module constant is not defined in the VM.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state cannot be accessed, an error is returned.
+    pub fn module_value(&self, interp: &mut Artichoke) -> Result<Option<Value>, Exception> {
+        let rclass = self.rclass();
+        let module = unsafe {
+            interp.with_ffi_boundary(|mrb| {
+                if let Some(mut rclass) = rclass.resolve(mrb) {
+                    let module = sys::mrb_sys_module_value(rclass.as_mut());
+                    Some(Value::from(module))
+                } else {
+                    None
+                }
+            })?
+        };
+        Ok(module)
+    }
+
+    /// Drop the memoized [`sys::RClass`] resolution for this spec.
+    ///
+    /// Cached `RClass` pointers remain valid while the module constant is
+    /// defined. Embedders that remove the constant out from under a
+    /// registered spec, for example with `Object#remove_const`, must
+    /// invalidate the cache before the next resolution.
+    pub fn invalidate_rclass_cache(&self) {
+        self.resolved.set(None);
     }
 }
 
@@ -330,4 +495,81 @@ mod tests {
         let rclass = unsafe { interp.with_ffi_boundary(|mrb| spec.rclass().resolve(mrb)) }.unwrap();
         assert!(rclass.is_some());
     }
+
+    #[test]
+    fn module_value_resolves_nested_module() {
+        let mut interp = crate::interpreter().unwrap();
+        let _ = interp.eval(b"module Scope; module Leaf; end; end").unwrap();
+        let scope = Spec::new(&mut interp, "Scope", None).unwrap();
+        let spec = Spec::new(&mut interp, "Leaf", Some(EnclosingRubyScope::module(&scope))).unwrap();
+        let module = spec.module_value(&mut interp).unwrap().unwrap();
+        let name = module.funcall(&mut interp, "name", &[], None).unwrap();
+        let name = name.try_into_mut::<&str>(&mut interp).unwrap();
+        assert_eq!("Scope::Leaf", name);
+
+        let spec = Spec::new(&mut interp, "NotDefinedAnywhere", None).unwrap();
+        assert!(spec.module_value(&mut interp).unwrap().is_none());
+    }
+
+    #[test]
+    fn rclass_failed_resolution_is_not_cached() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = Spec::new(&mut interp, "LateDefined", None).unwrap();
+        let rclass = unsafe { interp.with_ffi_boundary(|mrb| spec.rclass().resolve(mrb)) }.unwrap();
+        assert!(rclass.is_none());
+
+        // Defining the module after a failed resolution must be observable;
+        // only successful resolutions are memoized.
+        let _ = interp.eval(b"module LateDefined; end").unwrap();
+        let rclass = unsafe { interp.with_ffi_boundary(|mrb| spec.rclass().resolve(mrb)) }.unwrap();
+        assert!(rclass.is_some());
+        let again = unsafe { interp.with_ffi_boundary(|mrb| spec.rclass().resolve(mrb)) }.unwrap();
+        assert_eq!(rclass, again);
+    }
+
+    #[test]
+    fn define_nested_module_creates_namespace() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = Spec::new(&mut interp, "Net", None).unwrap();
+        module::Builder::for_spec(&mut interp, &spec)
+            .define_nested_module("HTTP")
+            .unwrap()
+            .define()
+            .unwrap();
+        let result = interp.eval(b"Net::HTTP.is_a?(Module)").unwrap();
+        assert!(result.try_into::<bool>(&interp).unwrap());
+
+        // Redefining an existing nested module is a no-op.
+        let _ = interp.eval(b"module Net; module HTTP; MARKER = 1; end; end").unwrap();
+        module::Builder::for_spec(&mut interp, &spec)
+            .define_nested_module("HTTP")
+            .unwrap()
+            .define()
+            .unwrap();
+        let marker = interp.eval(b"Net::HTTP::MARKER").unwrap();
+        assert_eq!(1, marker.try_into::<Int>(&interp).unwrap());
+    }
+
+    #[test]
+    fn define_nested_module_rejects_invalid_constant_names() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = Spec::new(&mut interp, "Net", None).unwrap();
+        let builder = module::Builder::for_spec(&mut interp, &spec);
+        assert!(builder.define_nested_module("HT\0TP").is_err());
+    }
+
+    struct Ephemeral;
+
+    #[test]
+    fn undef_module_removes_rust_binding() {
+        let mut interp = crate::interpreter().unwrap();
+        let spec = Spec::new(&mut interp, "Ephemeral", None).unwrap();
+        interp.def_module::<Ephemeral>(spec).unwrap();
+        assert!(interp.is_module_defined::<Ephemeral>());
+
+        let spec = interp.undef_module::<Ephemeral>().unwrap().unwrap();
+        assert_eq!("Ephemeral", spec.name().as_ref());
+        assert!(!interp.is_module_defined::<Ephemeral>());
+        assert!(interp.undef_module::<Ephemeral>().unwrap().is_none());
+    }
 }
@@ -212,6 +212,18 @@ where
         Some(value.as_ref())
     }
 
+    /// Removes and returns the [module spec](Spec) corresponding to the type
+    /// key.
+    ///
+    /// If the type `K` has not been registered, [`None`] is returned.
+    pub fn remove<K>(&mut self) -> Option<Box<Spec>>
+    where
+        K: Any,
+    {
+        let key = TypeId::of::<K>();
+        self.0.remove(&key)
+    }
+
     /// Reserves `capacity` for at least additional more elements to be inserted
     /// in the `Registry`. The collection may reserve more space to avoid
     /// frequent reallocations.
@@ -12,6 +12,10 @@ pub trait ModuleRegistry {
     where
         T: Any;
 
+    fn undef_module<T>(&mut self) -> Result<Option<module::Spec>, Exception>
+    where
+        T: Any;
+
     fn module_spec<T>(&self) -> Result<Option<&module::Spec>, Exception>
     where
         T: Any;
@@ -47,6 +51,23 @@ impl ModuleRegistry for Artichoke {
         Ok(())
     }
 
+    /// Remove the module definition bound to a Rust type `T` and return it.
+    ///
+    /// This function returns `None` if type `T` has not had a module spec
+    /// registered for it using [`ModuleRegistry::def_module`].
+    ///
+    /// Removing a spec only unbinds it on the Rust side; the module constant
+    /// remains defined in the live mruby VM. Removing the constant requires
+    /// evaling `remove_const` in the VM.
+    fn undef_module<T>(&mut self) -> Result<Option<module::Spec>, Exception>
+    where
+        T: Any,
+    {
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        let spec = state.modules.remove::<T>();
+        Ok(spec.map(|spec| *spec))
+    }
+
     /// Retrieve a module definition from the interpreter bound to Rust type `T`.
     ///
     /// This function returns `None` if type `T` has not had a module spec
@@ -0,0 +1,114 @@
+use std::fmt;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Construct the default [`Clock`] for an interpreter [`State`](super::State).
+#[must_use]
+pub fn clock() -> Box<dyn Clock> {
+    let clock = System::new();
+    Box::new(clock)
+}
+
+impl Default for Box<dyn Clock> {
+    fn default() -> Self {
+        clock()
+    }
+}
+
+/// Time sources required by an Artichoke interpreter.
+///
+/// Artichoke proxies all accesses to wall clock and monotonic time through a
+/// pluggable clock. Embedders can replace the clock to make time-dependent
+/// Ruby APIs like `Kernel#sleep` deterministic and non-blocking in tests.
+pub trait Clock: fmt::Debug {
+    /// Suspend execution for the given duration.
+    ///
+    /// Implementations may block the current thread or merely advance their
+    /// internal notion of time.
+    fn sleep(&mut self, duration: Duration);
+
+    /// Return the elapsed monotonic time since an arbitrary, fixed origin.
+    ///
+    /// Successive calls are guaranteed to return non-decreasing durations.
+    fn monotonic(&self) -> Duration;
+
+    /// Return the elapsed wall clock time since the Unix epoch.
+    fn realtime(&self) -> Duration;
+}
+
+/// A [`Clock`] backed by the host system.
+///
+/// `sleep` blocks the current thread with [`thread::sleep`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct System {
+    origin: Instant,
+}
+
+impl System {
+    /// Constructs a new `System` clock with a monotonic origin of now.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Default for System {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for System {
+    fn sleep(&mut self, duration: Duration) {
+        thread::sleep(duration);
+    }
+
+    fn monotonic(&self) -> Duration {
+        self.origin.elapsed()
+    }
+
+    fn realtime(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
+
+/// A virtual [`Clock`] that never blocks.
+///
+/// `sleep` advances the clock by the requested duration instead of suspending
+/// the current thread. `Mock` clocks make time-dependent Ruby APIs
+/// deterministic in tests.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Mock {
+    elapsed: Duration,
+}
+
+impl Mock {
+    /// Constructs a new `Mock` clock at time zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance the clock by the given duration.
+    pub fn advance(&mut self, duration: Duration) {
+        self.elapsed += duration;
+    }
+}
+
+impl Clock for Mock {
+    fn sleep(&mut self, duration: Duration) {
+        self.advance(duration);
+    }
+
+    fn monotonic(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn realtime(&self) -> Duration {
+        self.elapsed
+    }
+}
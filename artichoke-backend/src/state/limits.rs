@@ -0,0 +1,173 @@
+//! Per-eval resource limits for running untrusted code.
+//!
+//! [`ExecutionLimits`] bundles a VM step budget, a call-stack depth limit, and
+//! a heap-allocation cap into a single configuration that
+//! [`Artichoke::eval_limited`](crate::Artichoke::eval_limited) enforces for
+//! the duration of one eval. Exceeding a limit raises a distinct, catchable
+//! exception: `StepLimitExceeded`, `SystemStackError`, or `NoMemoryError`.
+
+use std::ffi::c_void;
+use std::mem;
+use std::ptr;
+
+use crate::state::State;
+use crate::sys;
+
+/// Resource limits applied to a single
+/// [`Artichoke::eval_limited`](crate::Artichoke::eval_limited) call.
+///
+/// All limits default to unlimited and compose: the first limit exceeded
+/// aborts the eval. Once a limit trips it is disarmed, so the raised
+/// exception can propagate through rescue and ensure clauses without
+/// immediately re-tripping.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[must_use]
+pub struct ExecutionLimits {
+    steps: Option<usize>,
+    stack_depth: Option<usize>,
+    heap: Option<usize>,
+}
+
+impl ExecutionLimits {
+    /// Construct a new, unlimited `ExecutionLimits`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cap the number of VM instructions the eval may execute.
+    ///
+    /// Exceeding the budget raises `StepLimitExceeded`.
+    pub fn with_step_limit(mut self, steps: usize) -> Self {
+        self.steps = Some(steps);
+        self
+    }
+
+    /// Cap the depth of the Ruby call stack in frames.
+    ///
+    /// Exceeding the limit raises `SystemStackError`, the same pre-allocated
+    /// exception the VM raises at its own compile-time depth limit.
+    pub fn with_stack_depth_limit(mut self, frames: usize) -> Self {
+        self.stack_depth = Some(frames);
+        self
+    }
+
+    /// Cap the number of bytes the eval may allocate from the mruby heap.
+    ///
+    /// Accounting is monotonic: bytes are counted when requested and are not
+    /// credited back when freed. Exceeding the cap makes the allocator return
+    /// `NULL`, which the VM reports as the pre-allocated `NoMemoryError`.
+    pub fn with_heap_limit(mut self, bytes: usize) -> Self {
+        self.heap = Some(bytes);
+        self
+    }
+}
+
+/// Live countdown state for an in-flight limited eval.
+///
+/// Stored in [`State::limits`] while `eval_limited` is on the stack and
+/// consulted by the VM hooks below.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ExecutionBudget {
+    steps_remaining: Option<usize>,
+    stack_depth: Option<usize>,
+    heap_remaining: Option<usize>,
+}
+
+impl From<ExecutionLimits> for ExecutionBudget {
+    fn from(limits: ExecutionLimits) -> Self {
+        Self {
+            steps_remaining: limits.steps,
+            stack_depth: limits.stack_depth,
+            heap_remaining: limits.heap,
+        }
+    }
+}
+
+/// Extract the live [`ExecutionBudget`] for an `mrb` instance, if any.
+///
+/// # Safety
+///
+/// `mrb` must be a valid interpreter. The returned reference aliases the
+/// `State` serialized into the `mrb` userdata pointer and must not outlive
+/// the caller's activation.
+unsafe fn budget<'a>(mrb: *mut sys::mrb_state) -> Option<&'a mut ExecutionBudget> {
+    // While the VM is executing, the `State` is serialized into the `mrb`
+    // userdata pointer. If the pointer is null, the state is on the Rust side
+    // of an FFI boundary and there is nothing to enforce.
+    let state = ((*mrb).ud as *mut State).as_mut()?;
+    state.limits.as_mut()
+}
+
+/// VM instruction hook that enforces the step and stack-depth budgets.
+///
+/// Installed on `mrb->code_fetch_hook` by `eval_limited` for the duration of
+/// the call. Requires mruby to be compiled with `MRB_ENABLE_DEBUG_HOOK`.
+pub(crate) unsafe extern "C" fn budget_code_fetch_hook(
+    mrb: *mut sys::mrb_state,
+    _irep: *mut sys::mrb_irep,
+    _pc: *const sys::mrb_code,
+    _regs: *mut sys::mrb_value,
+) {
+    let budget = if let Some(budget) = budget(mrb) {
+        budget
+    } else {
+        return;
+    };
+    if let Some(stack_depth) = budget.stack_depth {
+        let context = (*mrb).c;
+        let depth = ((*context).ci as usize).wrapping_sub((*context).cibase as usize)
+            / mem::size_of::<sys::mrb_callinfo>();
+        if depth > stack_depth {
+            // Disarm before raising so unwinding can run ensure clauses
+            // without re-tripping the limit.
+            budget.stack_depth = None;
+            sys::mrb_exc_raise(
+                mrb,
+                sys::mrb_sys_obj_value((*mrb).stack_err as *mut c_void),
+            );
+        }
+    }
+    if let Some(steps) = budget.steps_remaining {
+        if let Some(remaining) = steps.checked_sub(1) {
+            budget.steps_remaining = Some(remaining);
+        } else {
+            budget.steps_remaining = None;
+            sys::mrb_sys_raise(
+                mrb,
+                "StepLimitExceeded\0".as_ptr() as *const i8,
+                "step limit exceeded\0".as_ptr() as *const i8,
+            );
+        }
+    }
+}
+
+/// Allocator wrapper that enforces the heap budget.
+///
+/// Installed on `mrb->allocf` by `eval_limited` for the duration of the call.
+/// Delegates to [`sys::mrb_default_allocf`] while the budget holds; once the
+/// budget is exhausted, returns `NULL`, which the VM reports as the
+/// pre-allocated `NoMemoryError`.
+pub(crate) unsafe extern "C" fn budget_allocf(
+    mrb: *mut sys::mrb_state,
+    p: *mut c_void,
+    size: sys::size_t,
+    ud: *mut c_void,
+) -> *mut c_void {
+    // A zero size is a free and always passes through.
+    if size != 0 {
+        if let Some(budget) = budget(mrb) {
+            if let Some(heap) = budget.heap_remaining {
+                if let Some(remaining) = heap.checked_sub(size as usize) {
+                    budget.heap_remaining = Some(remaining);
+                } else {
+                    // Disarm before failing the allocation so the raised
+                    // `NoMemoryError` can be extracted and rescued without
+                    // starving the error path of memory.
+                    budget.heap_remaining = None;
+                    return ptr::null_mut();
+                }
+            }
+        }
+    }
+    sys::mrb_default_allocf(mrb, p, size, ud)
+}
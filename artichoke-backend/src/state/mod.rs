@@ -1,10 +1,13 @@
 use intaglio::bytes::SymbolTable;
+use std::collections::HashSet;
 
 use crate::class;
-use crate::fs::{self, Filesystem};
+use crate::fs::{self, Filesystem, LoadPathResolver};
 use crate::module;
 use crate::sys;
 
+pub mod clock;
+pub mod limits;
 pub mod output;
 pub mod parser;
 #[cfg(feature = "core-random")]
@@ -13,6 +16,8 @@ pub mod regexp;
 
 #[cfg(feature = "core-random")]
 use prng::Prng;
+#[cfg(feature = "stdlib-securerandom")]
+use crate::extn::stdlib::securerandom::EntropySource;
 
 /// Container for domain-specific interpreter state.
 #[derive(Default, Debug)]
@@ -21,11 +26,20 @@ pub struct State {
     pub classes: class::Registry,
     pub modules: module::Registry,
     pub vfs: Box<dyn Filesystem>,
+    pub resolver: Option<Box<dyn LoadPathResolver>>,
+    pub loaded_features: Vec<Vec<u8>>,
     pub regexp: regexp::State,
     pub symbols: SymbolTable,
     pub output: output::Strategy,
+    pub capture: Option<output::Captured>,
+    pub at_exit: Vec<sys::mrb_value>,
+    pub limits: Option<limits::ExecutionBudget>,
+    pub clock: Box<dyn clock::Clock>,
+    pub warned_messages: HashSet<Vec<u8>>,
     #[cfg(feature = "core-random")]
     pub prng: Prng,
+    #[cfg(feature = "stdlib-securerandom")]
+    pub secure_random: Option<Box<dyn EntropySource>>,
 }
 
 impl State {
@@ -40,6 +54,7 @@ impl State {
     /// - [Ruby parser and file context](parser::State).
     /// - [Intepreter-level PRNG](Prng) (behind the `core-random` feature).
     /// - [IO capturing](output::Strategy) strategy.
+    /// - [Pluggable clock](clock::Clock) for time-dependent APIs.
     #[must_use]
     pub fn new() -> Self {
         Self {
@@ -47,11 +62,20 @@ impl State {
             classes: class::Registry::new(),
             modules: module::Registry::new(),
             vfs: fs::filesystem(),
+            resolver: None,
+            loaded_features: Vec::new(),
             regexp: regexp::State::new(),
             symbols: SymbolTable::new(),
             output: output::Strategy::new(),
+            capture: None,
+            at_exit: Vec::new(),
+            limits: None,
+            clock: clock::clock(),
+            warned_messages: HashSet::new(),
             #[cfg(feature = "core-random")]
             prng: Prng::new(),
+            #[cfg(feature = "stdlib-securerandom")]
+            secure_random: None,
         }
     }
 
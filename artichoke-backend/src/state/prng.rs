@@ -1,10 +1,91 @@
+use rand::{RngCore, SeedableRng};
+use std::convert::TryInto;
+use std::error;
+use std::fmt;
+
 use crate::extn::core::random::backend::rand::{Rand, Rng};
 use crate::extn::core::random::backend::InternalState;
 use crate::types::{Fp, Int};
 
+/// Error returned when deserializing dumped PRNG state fails.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum PrngError {
+    /// The serialized state buffer had an unexpected length.
+    InvalidLength(usize),
+}
+
+impl fmt::Display for PrngError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => {
+                write!(f, "invalid serialized PRNG state of {} bytes", len)
+            }
+        }
+    }
+}
+
+impl error::Error for PrngError {}
+
+/// A PRNG adapter that counts raw 64-bit draws from the backing generator.
+///
+/// The generator state cannot be read out of the backing PRNG directly, but
+/// the state is fully determined by the seed and the number of draws. Every
+/// draw — including `u32` draws and byte fills — is routed through
+/// [`RngCore::next_u64`] so replaying the recorded number of draws against a
+/// freshly seeded generator reproduces the identical state.
+#[derive(Debug, Clone)]
+struct CountingRng {
+    rng: Rng,
+    draws: u64,
+}
+
+impl SeedableRng for CountingRng {
+    type Seed = <Rng as SeedableRng>::Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            rng: Rng::from_seed(seed),
+            draws: 0,
+        }
+    }
+
+    fn seed_from_u64(seed: u64) -> Self {
+        Self {
+            rng: Rng::seed_from_u64(seed),
+            draws: 0,
+        }
+    }
+}
+
+impl RngCore for CountingRng {
+    fn next_u32(&mut self) -> u32 {
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            self.next_u64() as u32
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.draws += 1;
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let block = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct Prng {
-    random: Rand<Rng>,
+    random: Rand<CountingRng>,
 }
 
 impl From<u64> for Prng {
@@ -33,6 +114,9 @@ impl Default for Prng {
 }
 
 impl Prng {
+    /// Length in bytes of the state buffer returned by [`Prng::dump_state`].
+    const DUMP_STATE_LEN: usize = 16;
+
     #[must_use]
     #[inline]
     pub fn new() -> Self {
@@ -56,6 +140,39 @@ impl Prng {
         self.random.internal_state()
     }
 
+    /// Serialize the full generator state, seed and position included.
+    ///
+    /// The returned buffer can be given to [`Prng::restore_state`] to resume
+    /// the generator with an identical subsequent output sequence.
+    #[must_use]
+    pub fn dump_state(&self) -> Vec<u8> {
+        let mut state = Vec::with_capacity(Self::DUMP_STATE_LEN);
+        state.extend_from_slice(&self.seed().to_le_bytes());
+        state.extend_from_slice(&self.random.inner().draws.to_le_bytes());
+        state
+    }
+
+    /// Restore generator state previously serialized with [`Prng::dump_state`].
+    ///
+    /// # Errors
+    ///
+    /// If `bytes` is not a buffer produced by [`Prng::dump_state`], an error is
+    /// returned.
+    pub fn restore_state(&mut self, bytes: &[u8]) -> Result<(), PrngError> {
+        if bytes.len() != Self::DUMP_STATE_LEN {
+            return Err(PrngError::InvalidLength(bytes.len()));
+        }
+        let (seed, draws) = bytes.split_at(8);
+        let seed = u64::from_le_bytes(seed.try_into().expect("seed is 8 bytes"));
+        let draws = u64::from_le_bytes(draws.try_into().expect("draw count is 8 bytes"));
+        let mut random = Rand::new(Some(seed));
+        for _ in 0..draws {
+            let _ = random.inner_mut().next_u64();
+        }
+        self.random = random;
+        Ok(())
+    }
+
     #[inline]
     pub fn bytes(&mut self, buf: &mut [u8]) {
         self.random.bytes(buf);
@@ -70,4 +187,79 @@ impl Prng {
     pub fn rand_float(&mut self, max: Option<Fp>) -> Fp {
         self.random.rand_float(max)
     }
+
+    /// Return a random `Integer` drawn uniformly from a range.
+    ///
+    /// The range starts at `start` and spans `len` values when `exclusive` is
+    /// true; when `exclusive` is false the endpoint `start + len` is also a
+    /// candidate. Callers must ensure the range is non-empty: `len` must be
+    /// positive when `exclusive` is true and non-negative otherwise.
+    #[inline]
+    pub fn rand_range(&mut self, start: Int, len: Int, exclusive: bool) -> Int {
+        let span = if exclusive { len } else { len + 1 };
+        start + self.random.rand_int(span)
+    }
+
+    /// Return a random `Float` drawn uniformly from `[start, start + len)`.
+    ///
+    /// Callers must ensure `len` is positive and finite.
+    #[inline]
+    pub fn rand_float_range(&mut self, start: Fp, len: Fp) -> Fp {
+        start + self.random.rand_float(Some(len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Prng;
+
+    #[test]
+    fn dump_and_restore_round_trip() {
+        let mut prng = Prng::from(42_u64);
+        let mut buf = [0; 12];
+        prng.bytes(&mut buf);
+        let _ = prng.rand_int(100);
+        let state = prng.dump_state();
+
+        let mut restored = Prng::from(7_u64);
+        restored.restore_state(&state).unwrap();
+        assert_eq!(restored.seed(), prng.seed());
+        for _ in 0..16 {
+            assert_eq!(prng.rand_int(1024), restored.rand_int(1024));
+        }
+        let mut expected = [0; 32];
+        let mut actual = [0; 32];
+        prng.bytes(&mut expected);
+        restored.bytes(&mut actual);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn restore_rejects_invalid_length() {
+        let mut prng = Prng::new();
+        assert!(prng.restore_state(b"truncated").is_err());
+    }
+
+    #[test]
+    fn rand_range_respects_bounds_and_exclusivity() {
+        let mut prng = Prng::from(42_u64);
+        let mut saw_endpoint = false;
+        for _ in 0..256 {
+            let exclusive = prng.rand_range(1, 2, true);
+            assert!(exclusive >= 1 && exclusive < 3);
+            let inclusive = prng.rand_range(1, 2, false);
+            assert!(inclusive >= 1 && inclusive <= 3);
+            saw_endpoint = saw_endpoint || inclusive == 3;
+        }
+        assert!(saw_endpoint);
+    }
+
+    #[test]
+    fn rand_float_range_is_half_open() {
+        let mut prng = Prng::from(42_u64);
+        for _ in 0..256 {
+            let num = prng.rand_float_range(1.5, 1.0);
+            assert!(num >= 1.5 && num < 2.5);
+        }
+    }
 }
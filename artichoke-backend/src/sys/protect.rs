@@ -88,7 +88,7 @@ impl<'a> Protect for Funcall<'a> {
         } = *Box::from_raw(ptr as *mut Self);
 
         // This will always unwrap because we've already checked that we
-        // have fewer than `MRB_FUNCALL_ARGC_MAX` args, which is less than
+        // have fewer than `MRB_FUNCALL_ARGV_MAX` args, which is less than
         // i64 max value.
         let argslen = if let Ok(argslen) = Int::try_from(args.len()) {
             argslen
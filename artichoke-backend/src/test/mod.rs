@@ -1 +1,39 @@
 pub mod prelude;
+
+use std::fs;
+use std::path::Path;
+
+use crate::core::Eval;
+use crate::Artichoke;
+
+/// Eval every `.rb` fixture in `dir` on the given interpreter.
+///
+/// Fixtures are ruby/spec-style scripts that raise on failure. Any exception
+/// raised by a fixture fails the calling test with the fixture's path and the
+/// exception message. Adding spec coverage for a feature is a matter of
+/// dropping a fixture into the feature's `fixtures` directory.
+pub fn run_specs<T>(interp: &mut Artichoke, dir: T)
+where
+    T: AsRef<Path>,
+{
+    let dir = dir.as_ref();
+    let entries = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("failed to read spec dir {}: {}", dir.display(), err));
+    let mut fixtures = entries
+        .map(|entry| entry.expect("failed to read spec dir entry").path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "rb"))
+        .collect::<Vec<_>>();
+    fixtures.sort();
+    assert!(
+        !fixtures.is_empty(),
+        "no spec fixtures found in {}",
+        dir.display()
+    );
+    for fixture in fixtures {
+        let spec = fs::read(&fixture)
+            .unwrap_or_else(|err| panic!("failed to read fixture {}: {}", fixture.display(), err));
+        if let Err(exception) = interp.eval(&spec) {
+            panic!("spec failure in {}: {}", fixture.display(), exception);
+        }
+    }
+}
@@ -1,26 +1,47 @@
 use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::error;
+use std::ffi::c_void;
 use std::fmt;
+use std::fmt::Write as _;
 use std::mem;
 use std::ptr;
+use std::slice;
 
 use crate::class_registry::ClassRegistry;
 use crate::convert::BoxUnboxVmValue;
 use crate::core::{Convert, ConvertMut, Intern, TryConvert, Value as ValueCore};
-use crate::exception::{Exception, RubyException};
+use crate::exception::{self, Exception, RubyException};
 use crate::exception_handler;
-use crate::extn::core::exception::{ArgumentError, Fatal, TypeError};
+use crate::extn::core::exception::{ArgumentError, Fatal, FrozenError, TypeError};
 use crate::extn::core::symbol::Symbol;
 use crate::gc::MrbGarbageCollection;
 use crate::sys::{self, protect};
 use crate::types::{self, Int, Ruby};
-use crate::Artichoke;
+use crate::{Artichoke, Guard};
 
 /// Max argument count for function calls including initialize and yield.
+///
+/// This mirrors the inline argument maximum of mruby's variadic `mrb_funcall`
+/// C API. Artichoke dispatches funcalls through `mrb_funcall_argv` with a
+/// heap-allocated argument buffer, and the VM packs longer argument lists
+/// into a Ruby `Array`, so calls from Rust are only limited by
+/// [`MRB_FUNCALL_ARGV_MAX`].
 pub const MRB_FUNCALL_ARGC_MAX: usize = 16;
 
+/// Max argument count the VM can receive in a single function call.
+///
+/// mruby raises a stack error when growing the VM stack past
+/// `MRB_STACK_MAX`, so funcalls with more arguments than this cannot be
+/// dispatched and fail with [`ArgCountError`].
+pub const MRB_FUNCALL_ARGV_MAX: usize = 0x40000;
+
 /// Boxed Ruby value in the [`Artichoke`] interpreter.
+///
+/// `Value` is [`Copy`]: cloning or copying a `Value` duplicates the
+/// `mrb_value` handle, not the Ruby object it points at. Use
+/// [`Value::dup`] or [`Value::clone_ruby`] to copy the underlying object
+/// with Ruby `dup`/`clone` semantics.
 #[derive(Default, Debug, Clone, Copy)]
 pub struct Value(sys::mrb_value);
 
@@ -106,6 +127,114 @@ impl Value {
         }
     }
 
+    /// Convert this value to a `Vec<Value>` with a strict `#to_a` call.
+    ///
+    /// Unlike the lenient `Kernel#Array` conversion, which falls back to
+    /// wrapping the receiver in a one-element `Array`, this conversion
+    /// requires the receiver to define `to_a` and to return an `Array`.
+    ///
+    /// # Errors
+    ///
+    /// If the receiver does not respond to `to_a`, a `NoMethodError` is
+    /// returned.
+    ///
+    /// If the underlying call to `#to_a` raises, the exception is returned.
+    ///
+    /// If `to_a` returns a non-`Array`, a [`TypeError`] is returned.
+    pub fn try_to_a(&self, interp: &mut Artichoke) -> Result<Vec<Self>, Exception> {
+        let result = self.funcall(interp, "to_a", &[], None)?;
+        if let Ruby::Array = result.ruby_type() {
+            let ary = result.try_into_mut::<Vec<Self>>(interp)?;
+            Ok(ary)
+        } else {
+            let mut message = String::from("can't convert ");
+            message.push_str(self.pretty_name(interp));
+            message.push_str(" to Array (");
+            message.push_str(self.pretty_name(interp));
+            message.push_str("#to_a gives ");
+            message.push_str(result.pretty_name(interp));
+            message.push(')');
+            Err(TypeError::from(message).into())
+        }
+    }
+
+    /// Guard a native mutation on this value by checking frozen state.
+    ///
+    /// Native methods that mutate their receiver should call this helper
+    /// before modifying any backing data so frozen receivers raise
+    /// `FrozenError` the way Ruby-defined mutators do.
+    ///
+    /// # Errors
+    ///
+    /// If this value is frozen, a `FrozenError` with the MRI message
+    /// `can't modify frozen X` is returned.
+    pub fn ensure_not_frozen(&self, interp: &mut Artichoke) -> Result<(), Exception> {
+        if self.is_frozen(interp) {
+            let mut message = String::from("can't modify frozen ");
+            message.push_str(self.pretty_name(interp));
+            Err(FrozenError::from(message).into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Duplicate the underlying Ruby object with Ruby's `dup` semantics.
+    ///
+    /// The derived Rust [`Clone`] on `Value` only copies the `mrb_value`
+    /// handle — both copies point at the same Ruby object. This function
+    /// performs a real shallow copy: the returned `Value` is a new object
+    /// that does not carry over frozen state or singleton methods.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying call to `#dup` raises, the exception is returned.
+    pub fn dup(&self, interp: &mut Artichoke) -> Result<Self, Exception> {
+        self.funcall(interp, "dup", &[], None)
+    }
+
+    /// Duplicate the underlying Ruby object with Ruby's `clone` semantics.
+    ///
+    /// Like [`dup`](Self::dup), this performs a real copy of the Ruby object
+    /// rather than a handle copy, but `clone` also preserves frozen state and
+    /// singleton methods.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying call to `#clone` raises, the exception is returned.
+    pub fn clone_ruby(&self, interp: &mut Artichoke) -> Result<Self, Exception> {
+        self.funcall(interp, "clone", &[], None)
+    }
+
+    /// Interpret this value as a tri-state boolean.
+    ///
+    /// Returns `Some(true)` for `true`, `Some(false)` for `false`, and `None`
+    /// for `nil`. All other values follow Ruby truthiness and map to
+    /// `Some(true)`.
+    ///
+    /// Unlike [`is_truthy`](Self::is_truthy), which collapses `nil` into
+    /// `false`, this function keeps `nil` distinct so callers can treat an
+    /// explicit `false` differently from an absent value.
+    #[must_use]
+    pub fn as_bool_or_nil(&self) -> Option<bool> {
+        let inner = self.inner();
+        if unsafe { sys::mrb_sys_value_is_nil(inner) } {
+            None
+        } else if unsafe { sys::mrb_sys_value_is_false(inner) } {
+            Some(false)
+        } else {
+            Some(true)
+        }
+    }
+
+    /// Whether this value is truthy in a Ruby conditional.
+    ///
+    /// Only `nil` and `false` are falsy in Ruby; every other value, including
+    /// `0` and the empty string, is truthy.
+    #[must_use]
+    pub fn is_truthy(&self) -> bool {
+        self.as_bool_or_nil().unwrap_or_default()
+    }
+
     /// Whether a value is an interpreter-only variant not exposed to Ruby.
     ///
     /// Some type tags like [`MRB_TT_UNDEF`](sys::mrb_vtype::MRB_TT_UNDEF) are
@@ -151,6 +280,392 @@ impl Value {
         }
     }
 
+    /// Read an instance variable from this value.
+    ///
+    /// Returns `nil` if the instance variable is not set.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying call to `#instance_variable_get` raises, the exception
+    /// is returned.
+    pub fn instance_variable(&self, interp: &mut Artichoke, name: &str) -> Result<Self, Exception> {
+        let name = interp.convert_mut(name);
+        self.funcall(interp, "instance_variable_get", &[name], None)
+    }
+
+    /// Set an instance variable on this value.
+    ///
+    /// This API allows Rust-defined [`RubyException`] impls to attach custom
+    /// attributes, e.g. `KeyError#key`, to the exception values they produce in
+    /// [`RubyException::as_mrb_value`].
+    ///
+    /// # Errors
+    ///
+    /// If the underlying call to `#instance_variable_set` raises, e.g. because
+    /// this value is frozen, the exception is returned.
+    pub fn set_instance_variable(
+        &self,
+        interp: &mut Artichoke,
+        name: &str,
+        value: Self,
+    ) -> Result<(), Exception> {
+        let name = interp.convert_mut(name);
+        let _ = self.funcall(interp, "instance_variable_set", &[name, value], None)?;
+        Ok(())
+    }
+
+    /// Like [`respond_to`](ValueCore::respond_to), with an explicit
+    /// `include_private` flag.
+    ///
+    /// The flag is forwarded as the second argument to Ruby's `respond_to?`,
+    /// so private methods are visible when it is true and
+    /// `respond_to_missing?` implementations that consult the flag are
+    /// honored.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying call to `respond_to?` raises, the exception is
+    /// returned.
+    pub fn respond_to_with_private(
+        &self,
+        interp: &mut Artichoke,
+        method: &str,
+        include_private: bool,
+    ) -> Result<bool, Exception> {
+        let method = interp.convert_mut(method);
+        let include_private = interp.convert(include_private);
+        let respond_to = self.funcall(interp, "respond_to?", &[method, include_private], None)?;
+        let respond_to = interp.try_convert(respond_to)?;
+        Ok(respond_to)
+    }
+
+    /// Call `func` on this value if the receiver responds to it.
+    ///
+    /// Returns `Ok(None)` when the method is undefined, which avoids the
+    /// `respond_to?`-then-`funcall` double dispatch callers otherwise write
+    /// by hand when probing for optional methods. Exceptions raised by the
+    /// method itself still surface as errors.
+    ///
+    /// # Errors
+    ///
+    /// If the `respond_to?` probe or the funcall raises, the exception is
+    /// returned.
+    pub fn try_funcall(
+        &self,
+        interp: &mut Artichoke,
+        func: &str,
+        args: &[Self],
+        block: Option<Self>,
+    ) -> Result<Option<Self>, Exception> {
+        if self.respond_to(interp, func)? {
+            let value = self.funcall(interp, func, args, block)?;
+            Ok(Some(value))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Call `func` on this value with a Rust closure as the block.
+    ///
+    /// The closure is exposed to Ruby as a `Proc`, so the called method can
+    /// `yield` to it like any other block. Block arguments are passed to the
+    /// closure as a slice of [`Value`]s and the closure's return value becomes
+    /// the value of the `yield` expression. Errors returned by the closure
+    /// are raised in the VM and propagate out of the funcall.
+    ///
+    /// # Safety and lifetimes
+    ///
+    /// The proc is only connected to the closure for the duration of the
+    /// funcall. If the called method retains the proc and Ruby code invokes
+    /// it after this method returns, the call raises `fatal` instead of
+    /// touching the closure, which may no longer exist. To guarantee a
+    /// retained proc never dereferences freed memory, a small heap allocation
+    /// holding the disarmed closure state is intentionally leaked on each
+    /// call.
+    ///
+    /// # Errors
+    ///
+    /// If the funcall or the closure raises, the exception is returned.
+    pub fn funcall_with_block<F>(
+        &self,
+        interp: &mut Artichoke,
+        func: &str,
+        args: &[Self],
+        block: F,
+    ) -> Result<Self, Exception>
+    where
+        F: FnMut(&mut Artichoke, &[Self]) -> Result<Self, Exception>,
+    {
+        let state = Box::new(FuncallBlockState {
+            closure: Some(Box::new(block)),
+        });
+        let state = Box::into_raw(state);
+        let block = unsafe {
+            interp.with_ffi_boundary(|mrb| {
+                let env = [sys::mrb_sys_cptr_value(mrb, state as *mut c_void)];
+                let proc = sys::mrb_proc_new_cfunc_with_env(
+                    mrb,
+                    Some(funcall_block_trampoline),
+                    1,
+                    env.as_ptr(),
+                );
+                sys::mrb_sys_obj_value(proc.cast::<c_void>())
+            })?
+        };
+        let result = self.funcall(interp, func, args, Some(Self::from(block)));
+        // Disarm the proc in case the called method retained it. This drops
+        // the closure and releases its borrows; the state allocation itself
+        // is leaked, see the safety discussion in the doc comment.
+        unsafe {
+            (*state).closure = None;
+        }
+        result
+    }
+
+    /// Iterate this value by calling its `each` method with a Rust closure
+    /// block.
+    ///
+    /// Unlike extracting a `Vec<Value>`, this works for any object that
+    /// responds to `each` — including custom `Enumerable`s — and does not
+    /// materialize the full collection before yielding elements.
+    ///
+    /// # Errors
+    ///
+    /// If the value does not respond to `each`, a `NoMethodError` is returned.
+    /// If `each` or the closure raises, the exception is returned and
+    /// iteration stops.
+    pub fn each<F>(&self, interp: &mut Artichoke, mut func: F) -> Result<(), Exception>
+    where
+        F: FnMut(&mut Artichoke, Self) -> Result<(), Exception>,
+    {
+        let _ = self.funcall_with_block(interp, "each", &[], |interp, args| {
+            let elem = args.first().copied().unwrap_or_else(Self::nil);
+            func(interp, elem)?;
+            Ok(Self::nil())
+        })?;
+        Ok(())
+    }
+
+    /// Maximum length in bytes of the `inspect` output captured by
+    /// [`Value::describe`] before truncation.
+    pub const INSPECT_TRUNCATE_LEN: usize = 64;
+
+    /// Summarize this value for diagnostics.
+    ///
+    /// The returned [`ValueInfo`] bundles the value's type, class name,
+    /// frozen state, `object_id`, and a truncated `inspect` representation,
+    /// which is the information a REPL `ls`-style listing displays per
+    /// binding.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying call to `#object_id` raises, the exception is
+    /// returned.
+    pub fn describe(&self, interp: &mut Artichoke) -> Result<ValueInfo, Exception> {
+        let object_id = self.funcall(interp, "object_id", &[], None)?;
+        let object_id = object_id.try_into::<Int>(interp)?;
+        let mut inspect = self.inspect(interp);
+        if inspect.len() > Self::INSPECT_TRUNCATE_LEN {
+            inspect.truncate(Self::INSPECT_TRUNCATE_LEN);
+            inspect.extend_from_slice(b"...");
+        }
+        Ok(ValueInfo {
+            ruby_type: self.ruby_type(),
+            class_name: String::from(self.pretty_name(interp)),
+            frozen: self.is_frozen(interp),
+            object_id,
+            inspect,
+        })
+    }
+
+    /// Run a closure with this value and return the value unchanged.
+    ///
+    /// This combinator mirrors Ruby's `Object#tap` at the Rust API level and
+    /// streamlines builder-style chains, e.g. freeze-then-return.
+    ///
+    /// # Errors
+    ///
+    /// If the given closure returns an error, the error is returned instead of
+    /// the value.
+    pub fn tap<F>(self, interp: &mut Artichoke, func: F) -> Result<Self, Exception>
+    where
+        F: FnOnce(&Self, &mut Artichoke) -> Result<(), Exception>,
+    {
+        func(&self, interp)?;
+        Ok(self)
+    }
+
+    /// Convert this value into a Rust type using a unified entry point.
+    ///
+    /// The [`try_into`](ValueCore::try_into) and
+    /// [`try_into_mut`](ValueCore::try_into_mut) split — immutable vs mutable
+    /// interpreter access — is a frequent source of borrow errors for callers
+    /// that pick the wrong one. This method always takes a mutable interpreter
+    /// and dispatches to the right underlying conversion, so prefer it over
+    /// choosing between the two.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying conversion fails, an error is returned.
+    pub fn convert_into<T>(self, interp: &mut Artichoke) -> Result<T, Exception>
+    where
+        T: ConvertFromValue,
+    {
+        T::convert_from(self, interp)
+    }
+
+    /// Deeply compare this value to another value.
+    ///
+    /// `Array`s and `Hash`es are compared element-wise by recursing into their
+    /// contents. All other values are compared with Ruby `==`.
+    ///
+    /// Cycles are detected by tracking pairs of visited containers. Revisiting
+    /// a pair during the recursion is treated as equal, matching the fixpoint
+    /// semantics MRI uses for recursive structures.
+    ///
+    /// This API is more convenient than funcalling `==` for asserting that a
+    /// Ruby value deeply equals an expected structure in tests.
+    ///
+    /// # Errors
+    ///
+    /// If an underlying call to `==` or a container accessor raises, the
+    /// exception is returned.
+    pub fn deep_eq(&self, interp: &mut Artichoke, other: &Self) -> Result<bool, Exception> {
+        let mut seen = Vec::new();
+        self.deep_eq_inner(interp, other, &mut seen)
+    }
+
+    fn deep_eq_inner(
+        &self,
+        interp: &mut Artichoke,
+        other: &Self,
+        seen: &mut Vec<(Self, Self)>,
+    ) -> Result<bool, Exception> {
+        match (self.ruby_type(), other.ruby_type()) {
+            (Ruby::Array, Ruby::Array) => {
+                if seen.iter().any(|(left, right)| left == self && right == other) {
+                    return Ok(true);
+                }
+                seen.push((*self, *other));
+                let left_len = self.funcall(interp, "length", &[], None)?;
+                let left_len = left_len.try_into::<Int>(interp)?;
+                let right_len = other.funcall(interp, "length", &[], None)?;
+                let right_len = right_len.try_into::<Int>(interp)?;
+                if left_len != right_len {
+                    seen.pop();
+                    return Ok(false);
+                }
+                for idx in 0..left_len {
+                    let idx = interp.convert(idx);
+                    let left = self.funcall(interp, "[]", &[idx], None)?;
+                    let right = other.funcall(interp, "[]", &[idx], None)?;
+                    if !left.deep_eq_inner(interp, &right, seen)? {
+                        seen.pop();
+                        return Ok(false);
+                    }
+                }
+                seen.pop();
+                Ok(true)
+            }
+            (Ruby::Hash, Ruby::Hash) => {
+                if seen.iter().any(|(left, right)| left == self && right == other) {
+                    return Ok(true);
+                }
+                seen.push((*self, *other));
+                let left_len = self.funcall(interp, "length", &[], None)?;
+                let left_len = left_len.try_into::<Int>(interp)?;
+                let right_len = other.funcall(interp, "length", &[], None)?;
+                let right_len = right_len.try_into::<Int>(interp)?;
+                if left_len != right_len {
+                    seen.pop();
+                    return Ok(false);
+                }
+                let keys = self.funcall(interp, "keys", &[], None)?;
+                let keys = keys.try_into_mut::<Vec<Self>>(interp)?;
+                for key in keys {
+                    let contains = other.funcall(interp, "key?", &[key], None)?;
+                    if !contains.try_into::<bool>(interp)? {
+                        seen.pop();
+                        return Ok(false);
+                    }
+                    let left = self.funcall(interp, "[]", &[key], None)?;
+                    let right = other.funcall(interp, "[]", &[key], None)?;
+                    if !left.deep_eq_inner(interp, &right, seen)? {
+                        seen.pop();
+                        return Ok(false);
+                    }
+                }
+                seen.pop();
+                Ok(true)
+            }
+            _ => {
+                let eq = self.funcall(interp, "==", &[*other], None)?;
+                eq.try_into::<bool>(interp)
+            }
+        }
+    }
+
+    /// Produce a deep copy of `self`.
+    ///
+    /// Nested `Array`s and `Hash`es are copied into new objects throughout so
+    /// mutating the copy cannot be observed through the original. All other
+    /// values — including immutable scalars — are shared with the original.
+    ///
+    /// This API is useful when exposing mutable copies of host-owned config
+    /// structures to scripts.
+    ///
+    /// # Errors
+    ///
+    /// If the structure contains a cycle, an `ArgumentError` is returned. If
+    /// an underlying container accessor raises, the exception is returned.
+    pub fn deep_dup(&self, interp: &mut Artichoke) -> Result<Self, Exception> {
+        let mut seen = Vec::new();
+        self.deep_dup_inner(interp, &mut seen)
+    }
+
+    fn deep_dup_inner(
+        &self,
+        interp: &mut Artichoke,
+        seen: &mut Vec<Self>,
+    ) -> Result<Self, Exception> {
+        match self.ruby_type() {
+            Ruby::Array => {
+                if seen.iter().any(|container| container == self) {
+                    return Err(ArgumentError::from("can't deep dup recursive container").into());
+                }
+                seen.push(*self);
+                let len = self.funcall(interp, "length", &[], None)?;
+                let len = len.try_into::<Int>(interp)?;
+                let mut dup = Vec::new();
+                for idx in 0..len {
+                    let idx = interp.convert(idx);
+                    let element = self.funcall(interp, "[]", &[idx], None)?;
+                    dup.push(element.deep_dup_inner(interp, seen)?);
+                }
+                seen.pop();
+                interp.try_convert_mut(dup)
+            }
+            Ruby::Hash => {
+                if seen.iter().any(|container| container == self) {
+                    return Err(ArgumentError::from("can't deep dup recursive container").into());
+                }
+                seen.push(*self);
+                let keys = self.funcall(interp, "keys", &[], None)?;
+                let keys = keys.try_into_mut::<Vec<Self>>(interp)?;
+                let mut pairs = Vec::with_capacity(keys.len());
+                for key in keys {
+                    let value = self.funcall(interp, "[]", &[key], None)?;
+                    let key = key.deep_dup_inner(interp, seen)?;
+                    let value = value.deep_dup_inner(interp, seen)?;
+                    pairs.push((key, value));
+                }
+                seen.pop();
+                Ok(interp.convert_mut(pairs))
+            }
+            _ => Ok(*self),
+        }
+    }
+
     pub fn implicitly_convert_to_int(&self, interp: &mut Artichoke) -> Result<Int, TypeError> {
         let int = if let Ok(int) = self.try_into::<Option<Int>>(interp) {
             if let Some(int) = int {
@@ -160,24 +675,17 @@ impl Value {
                     "no implicit conversion from nil to integer",
                 ));
             }
-        } else if let Ok(true) = self.respond_to(interp, "to_int") {
-            if let Ok(maybe) = self.funcall(interp, "to_int", &[], None) {
-                if let Ok(int) = maybe.try_into::<Int>(interp) {
-                    int
-                } else {
-                    let mut message = String::from("can't convert ");
-                    message.push_str(self.pretty_name(interp));
-                    message.push_str(" to Integer (");
-                    message.push_str(self.pretty_name(interp));
-                    message.push_str("#to_int gives ");
-                    message.push_str(maybe.pretty_name(interp));
-                    message.push(')');
-                    return Err(TypeError::from(message));
-                }
+        } else if let Ok(Some(maybe)) = self.try_funcall(interp, "to_int", &[], None) {
+            if let Ok(int) = maybe.try_into::<Int>(interp) {
+                int
             } else {
-                let mut message = String::from("no implicit conversion of ");
+                let mut message = String::from("can't convert ");
                 message.push_str(self.pretty_name(interp));
-                message.push_str(" into Integer");
+                message.push_str(" to Integer (");
+                message.push_str(self.pretty_name(interp));
+                message.push_str("#to_int gives ");
+                message.push_str(maybe.pretty_name(interp));
+                message.push(')');
                 return Err(TypeError::from(message));
             }
         } else {
@@ -234,6 +742,99 @@ impl Value {
         Ok(string)
     }
 
+    /// Extract the byte contents of this value, accepting only `String`
+    /// objects.
+    ///
+    /// Unlike [`Value::implicitly_convert_to_string`], this method performs no
+    /// coercion: symbols and objects that respond to `to_str` are rejected.
+    /// APIs that must distinguish real `String`s from coercible objects should
+    /// prefer this method.
+    ///
+    /// # Errors
+    ///
+    /// If the value is not a `Ruby::String`, a [`TypeError`] is returned.
+    pub fn as_string_strict(&self, interp: &mut Artichoke) -> Result<&[u8], TypeError> {
+        if let Ruby::String = self.ruby_type() {
+            if let Ok(string) = self.try_into_mut::<&[u8]>(interp) {
+                return Ok(string);
+            }
+        }
+        let mut message = String::from("wrong argument type ");
+        message.push_str(self.pretty_name(interp));
+        message.push_str(" (expected String)");
+        Err(TypeError::from(message))
+    }
+
+    /// Convert this value to a [`Symbol`], accepting `Symbol` and `String`
+    /// arguments.
+    ///
+    /// A `Ruby::Symbol` is unboxed directly. Values that respond to `to_str`
+    /// are interned, matching MRI's acceptance of strings where symbols are
+    /// expected.
+    ///
+    /// # Errors
+    ///
+    /// If the value is neither a `Symbol` nor convertible to a `String` with
+    /// `#to_str`, a [`TypeError`] is returned.
+    pub fn try_convert_to_symbol(&self, interp: &mut Artichoke) -> Result<Symbol, TypeError> {
+        if let Ruby::Symbol = self.ruby_type() {
+            let mut value = *self;
+            // Infallible because of Symbol ruby type
+            let sym = unsafe { Symbol::unbox_from_value(&mut value, interp).unwrap() };
+            return Ok(*sym);
+        }
+        if let Ok(bytes) = self.implicitly_convert_to_string(interp) {
+            let bytes = bytes.to_vec();
+            let sym = interp.intern_bytes(bytes).map_err(|_| {
+                let mut message = String::from("could not intern ");
+                message.push_str(self.pretty_name(interp));
+                TypeError::from(message)
+            })?;
+            return Ok(Symbol::from(sym));
+        }
+        let mut message = String::new();
+        message.push_str(self.pretty_name(interp));
+        message.push_str(" is not a symbol nor a string");
+        Err(TypeError::from(message))
+    }
+
+    /// Call `func` on this value and convert the result to a [`Symbol`].
+    ///
+    /// # Errors
+    ///
+    /// If the funcall raises, the exception is returned.
+    ///
+    /// If the result of the funcall is neither a `Symbol` nor convertible to a
+    /// `String` with `#to_str`, a [`TypeError`] is returned.
+    pub fn funcall_symbol(
+        &self,
+        interp: &mut Artichoke,
+        func: &str,
+        args: &[Self],
+        block: Option<Self>,
+    ) -> Result<Symbol, Exception> {
+        let result = self.funcall(interp, func, args, block)?;
+        let sym = result.try_convert_to_symbol(interp)?;
+        Ok(sym)
+    }
+
+    /// Adapt an optional argument extracted with [`mrb_get_args!`].
+    ///
+    /// Collapses an absent argument and an explicit `nil` into `None`,
+    /// matching Ruby's treatment of trailing optional arguments. Callers give
+    /// present arguments a typed interpretation — for example with
+    /// [`implicitly_convert_to_int`](Self::implicitly_convert_to_int), which
+    /// raises a `TypeError` on mismatch.
+    #[must_use]
+    pub fn from_optional_arg(arg: Option<sys::mrb_value>) -> Option<Self> {
+        let value = Self::from(arg?);
+        if value.is_nil() {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
     #[inline]
     pub fn implicitly_convert_to_nilable_string(
         &self,
@@ -247,6 +848,144 @@ impl Value {
     }
 }
 
+/// Diagnostic summary of a [`Value`], produced by [`Value::describe`].
+///
+/// `ValueInfo` bundles the accessors a REPL `ls`-style listing needs —
+/// type, class, frozen state, identity, and a display representation — into
+/// one call so callers do not have to sequence five funcalls themselves.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ValueInfo {
+    /// The [`Ruby`] type tag of the value.
+    pub ruby_type: Ruby,
+    /// The name of the value's class.
+    pub class_name: String,
+    /// Whether the value is frozen.
+    pub frozen: bool,
+    /// The value's `object_id`.
+    pub object_id: Int,
+    /// The value's `inspect` output, truncated to
+    /// [`Value::INSPECT_TRUNCATE_LEN`] bytes with a trailing ellipsis.
+    pub inspect: Vec<u8>,
+}
+
+/// Hashable key for Rust-side caches based on Ruby object identity.
+///
+/// `ObjectIdKey` captures the receiver's `object_id`, so two keys compare
+/// equal exactly when they were created from the same Ruby object (or from
+/// equal immediates like `Fixnum`s and `Symbol`s). This makes the type
+/// suitable for `HashMap<ObjectIdKey, T>` caches keyed by object identity.
+///
+/// Holding a key does **not** keep the underlying object alive in the
+/// garbage collector. If the object is collected, its `object_id` may be
+/// reused by a new allocation, so caches must root their keyed objects
+/// elsewhere (e.g. in a global or an arena) for as long as the entry is live.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ObjectIdKey(Int);
+
+impl ObjectIdKey {
+    /// Construct a key from a value's `object_id`.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying call to `#object_id` raises, the exception is
+    /// returned.
+    pub fn try_from_value(value: Value, interp: &mut Artichoke) -> Result<Self, Exception> {
+        let id = value.funcall(interp, "object_id", &[], None)?;
+        let id = id.try_into::<Int>(interp)?;
+        Ok(Self(id))
+    }
+
+    /// The raw `object_id` this key wraps.
+    #[must_use]
+    pub fn id(self) -> Int {
+        self.0
+    }
+}
+
+/// Closure state for [`Value::funcall_with_block`].
+///
+/// The state is heap-allocated and intentionally leaked so a proc retained by
+/// Ruby code beyond the funcall observes a disarmed state instead of a
+/// dangling pointer.
+struct FuncallBlockState<'a> {
+    closure: Option<Box<dyn FnMut(&mut Artichoke, &[Value]) -> Result<Value, Exception> + 'a>>,
+}
+
+unsafe extern "C" fn funcall_block_trampoline(
+    mrb: *mut sys::mrb_state,
+    _slf: sys::mrb_value,
+) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    // The closure state is stashed as a `cptr` in the proc environment by
+    // `Value::funcall_with_block`.
+    let env = sys::mrb_proc_cfunc_env_get(mrb, 0);
+    let state = sys::mrb_sys_cptr_ptr(env) as *mut FuncallBlockState<'_>;
+    let mut interp = unwrap_interpreter!(mrb);
+    let mut guard = Guard::new(&mut interp);
+    let args = args.iter().copied().map(Value::from).collect::<Vec<_>>();
+    let result = if let Some(ref mut closure) = (*state).closure {
+        closure(&mut guard, args.as_slice())
+    } else {
+        Err(Fatal::from("Funcall block invoked after funcall returned").into())
+    };
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => {
+            drop(args);
+            exception::raise(guard, exception)
+        }
+    }
+}
+
+/// Dispatch trait for [`Value::convert_into`].
+///
+/// Implementations select the correct underlying conversion —
+/// [`TryConvert`] for types that can be extracted with an immutable
+/// interpreter and [`TryConvertMut`](crate::core::TryConvertMut) for types
+/// that allocate on the interpreter heap.
+pub trait ConvertFromValue: Sized {
+    /// Convert a [`Value`] into this type with the given interpreter.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying conversion fails, an error is returned.
+    fn convert_from(value: Value, interp: &mut Artichoke) -> Result<Self, Exception>;
+}
+
+macro_rules! convert_from_value_impl {
+    (immutable => $($ty:ty),+ $(,)?) => {
+        $(
+            impl ConvertFromValue for $ty {
+                fn convert_from(value: Value, interp: &mut Artichoke) -> Result<Self, Exception> {
+                    value.try_into(interp)
+                }
+            }
+        )+
+    };
+    (mutable => $($ty:ty),+ $(,)?) => {
+        $(
+            impl ConvertFromValue for $ty {
+                fn convert_from(value: Value, interp: &mut Artichoke) -> Result<Self, Exception> {
+                    value.try_into_mut(interp)
+                }
+            }
+        )+
+    };
+}
+
+convert_from_value_impl!(immutable => bool, Option<bool>, Int, Option<Int>, types::Fp, u32, usize);
+convert_from_value_impl!(
+    mutable =>
+    String,
+    Option<String>,
+    Vec<u8>,
+    Option<Vec<u8>>,
+    Vec<Value>,
+    Vec<Int>,
+    Vec<String>,
+    Vec<Vec<u8>>,
+);
+
 impl ValueCore for Value {
     type Artichoke = Artichoke;
     type Arg = Self;
@@ -266,7 +1005,6 @@ impl ValueCore for Value {
             warn!("{}", arg_count_error);
             return Err(arg_count_error.into());
         }
-        let args = args.iter().map(Self::inner).collect::<Vec<_>>();
         trace!(
             "Calling {}#{} with {} args{}",
             self.ruby_type(),
@@ -274,14 +1012,42 @@ impl ValueCore for Value {
             args.len(),
             if block.is_some() { " and block" } else { "" }
         );
-        let func = arena.intern_string(func.to_string())?;
+        // Zero- and one-argument calls dominate funcall traffic. Marshal
+        // arguments into a fixed-size stack buffer for common arities and only
+        // fall back to a heap `Vec` for longer argument lists. Both buffers
+        // outlive the call to `protect::funcall` below, so the pointer handed
+        // to the VM remains valid for the duration of the call.
+        const STACK_ARGS_MAX: usize = 8;
+        let mut stack_args = [mem::MaybeUninit::<sys::mrb_value>::uninit(); STACK_ARGS_MAX];
+        let heap_args;
+        let args = if args.len() <= STACK_ARGS_MAX {
+            for (slot, arg) in stack_args.iter_mut().zip(args.iter()) {
+                *slot = mem::MaybeUninit::new(arg.inner());
+            }
+            // Safety: the first `args.len()` slots were initialized by the
+            // loop above and `MaybeUninit<T>` has the same layout as `T`.
+            unsafe {
+                slice::from_raw_parts(stack_args.as_ptr() as *const sys::mrb_value, args.len())
+            }
+        } else {
+            heap_args = args.iter().map(Self::inner).collect::<Vec<_>>();
+            heap_args.as_slice()
+        };
+        // Interning requires an owned `String`, so probe the symbol table
+        // first. Hot method names like `to_s` and `inspect` are interned once
+        // and resolved without allocating on every subsequent call.
+        let func = if let Some(sym) = arena.check_interned_string(func)? {
+            sym
+        } else {
+            arena.intern_string(func.to_string())?
+        };
         let result = unsafe {
             arena.with_ffi_boundary(|mrb| {
                 protect::funcall(
                     mrb,
                     self.inner(),
                     func.into(),
-                    args.as_slice(),
+                    args,
                     block.as_ref().map(Self::inner),
                 )
             })?
@@ -321,10 +1087,22 @@ impl ValueCore for Value {
 
     fn inspect(&self, interp: &mut Self::Artichoke) -> Vec<u8> {
         if let Ok(display) = self.funcall(interp, "inspect", &[], None) {
-            display.try_into_mut(interp).unwrap_or_default()
-        } else {
-            Vec::new()
+            if let Ok(display) = display.try_into_mut::<Vec<u8>>(interp) {
+                return display;
+            }
+        }
+        // If `inspect` raises or returns a non-String, fall back to a default
+        // `#<ClassName:0x...>` representation like MRI's `Object#inspect` so
+        // diagnostics remain useful.
+        let mut display = String::from("#<");
+        display.push_str(self.pretty_name(interp));
+        if let Ok(object_id) = self.funcall(interp, "object_id", &[], None) {
+            if let Ok(object_id) = object_id.try_into::<Int>(interp) {
+                let _ = write!(&mut display, ":0x{:x}", object_id);
+            }
         }
+        display.push('>');
+        display.into_bytes()
     }
 
     fn is_nil(&self) -> bool {
@@ -332,9 +1110,7 @@ impl ValueCore for Value {
     }
 
     fn respond_to(&self, interp: &mut Self::Artichoke, method: &str) -> Result<bool, Self::Error> {
-        let method = interp.convert_mut(method);
-        let respond_to = self.funcall(interp, "respond_to?", &[method], None)?;
-        interp.try_convert(respond_to)
+        self.respond_to_with_private(interp, method, false)
     }
 
     fn to_s(&self, interp: &mut Self::Artichoke) -> Vec<u8> {
@@ -371,10 +1147,10 @@ impl TryFrom<Vec<Value>> for ArgCountError {
     type Error = ();
 
     fn try_from(args: Vec<Value>) -> Result<Self, Self::Error> {
-        if args.len() > MRB_FUNCALL_ARGC_MAX {
+        if args.len() > MRB_FUNCALL_ARGV_MAX {
             Ok(Self {
                 given: args.len(),
-                max: MRB_FUNCALL_ARGC_MAX,
+                max: MRB_FUNCALL_ARGV_MAX,
             })
         } else {
             Err(())
@@ -386,10 +1162,10 @@ impl TryFrom<Vec<sys::mrb_value>> for ArgCountError {
     type Error = ();
 
     fn try_from(args: Vec<sys::mrb_value>) -> Result<Self, Self::Error> {
-        if args.len() > MRB_FUNCALL_ARGC_MAX {
+        if args.len() > MRB_FUNCALL_ARGV_MAX {
             Ok(Self {
                 given: args.len(),
-                max: MRB_FUNCALL_ARGC_MAX,
+                max: MRB_FUNCALL_ARGV_MAX,
             })
         } else {
             Err(())
@@ -401,10 +1177,10 @@ impl TryFrom<&[Value]> for ArgCountError {
     type Error = ();
 
     fn try_from(args: &[Value]) -> Result<Self, Self::Error> {
-        if args.len() > MRB_FUNCALL_ARGC_MAX {
+        if args.len() > MRB_FUNCALL_ARGV_MAX {
             Ok(Self {
                 given: args.len(),
-                max: MRB_FUNCALL_ARGC_MAX,
+                max: MRB_FUNCALL_ARGV_MAX,
             })
         } else {
             Err(())
@@ -416,10 +1192,10 @@ impl TryFrom<&[sys::mrb_value]> for ArgCountError {
     type Error = ();
 
     fn try_from(args: &[sys::mrb_value]) -> Result<Self, Self::Error> {
-        if args.len() > MRB_FUNCALL_ARGC_MAX {
+        if args.len() > MRB_FUNCALL_ARGV_MAX {
             Ok(Self {
                 given: args.len(),
-                max: MRB_FUNCALL_ARGC_MAX,
+                max: MRB_FUNCALL_ARGV_MAX,
             })
         } else {
             Err(())
@@ -433,7 +1209,7 @@ impl ArgCountError {
     pub fn new() -> Self {
         Self {
             given: 0,
-            max: MRB_FUNCALL_ARGC_MAX,
+            max: MRB_FUNCALL_ARGV_MAX,
         }
     }
 }
@@ -522,6 +1298,226 @@ mod tests {
         assert_eq!(debug, b"true");
     }
 
+    #[test]
+    fn object_id_key_identifies_objects_in_a_map() {
+        use std::collections::HashMap;
+
+        use super::ObjectIdKey;
+
+        let mut interp = crate::interpreter().unwrap();
+
+        let first = interp.eval(b"$first = Object.new").unwrap();
+        let second = interp.eval(b"$second = Object.new").unwrap();
+        let first_key = ObjectIdKey::try_from_value(first, &mut interp).unwrap();
+        let second_key = ObjectIdKey::try_from_value(second, &mut interp).unwrap();
+        assert_ne!(first_key, second_key);
+
+        let mut cache = HashMap::new();
+        cache.insert(first_key, "first");
+        cache.insert(second_key, "second");
+
+        // Another reference to the same object produces an equal key.
+        let first_again = interp.eval(b"$first").unwrap();
+        let key = ObjectIdKey::try_from_value(first_again, &mut interp).unwrap();
+        assert_eq!(Some(&"first"), cache.get(&key));
+    }
+
+    #[test]
+    fn respond_to_with_private_accepts_defined_methods() {
+        let mut interp = crate::interpreter().unwrap();
+
+        // `Module#private` is a no-op in mruby, so defined methods are
+        // visible to `respond_to?` regardless of the flag. The flag is still
+        // forwarded to `respond_to_missing?` implementations, which is
+        // covered below.
+        let value = interp
+            .eval(b"class Secretive; private; def secret; end; end; Secretive.new")
+            .unwrap();
+        assert!(value
+            .respond_to_with_private(&mut interp, "secret", false)
+            .unwrap());
+        assert!(value
+            .respond_to_with_private(&mut interp, "secret", true)
+            .unwrap());
+        assert!(!value
+            .respond_to_with_private(&mut interp, "not_a_method", true)
+            .unwrap());
+    }
+
+    #[test]
+    fn respond_to_with_private_honors_respond_to_missing() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let value = interp
+            .eval(
+                br#"
+                class Ghostly
+                  def respond_to_missing?(method, include_private = false)
+                    method == :ghost || (include_private && method == :private_ghost)
+                  end
+                end
+                Ghostly.new
+                "#,
+            )
+            .unwrap();
+        assert!(value.respond_to(&mut interp, "ghost").unwrap());
+        assert!(value
+            .respond_to_with_private(&mut interp, "ghost", true)
+            .unwrap());
+        assert!(!value.respond_to(&mut interp, "private_ghost").unwrap());
+        assert!(!value
+            .respond_to_with_private(&mut interp, "private_ghost", false)
+            .unwrap());
+        assert!(value
+            .respond_to_with_private(&mut interp, "private_ghost", true)
+            .unwrap());
+    }
+
+    #[test]
+    fn try_funcall_returns_none_for_undefined_methods() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let value = interp.eval(b"Object.new").unwrap();
+        let result = value
+            .try_funcall(&mut interp, "not_a_method", &[], None)
+            .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn try_funcall_dispatches_defined_methods() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let value = interp.eval(b"'artichoke'").unwrap();
+        let result = value
+            .try_funcall(&mut interp, "length", &[], None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(9, result.try_into::<Int>(&interp).unwrap());
+    }
+
+    #[test]
+    fn try_funcall_surfaces_exceptions_from_the_method() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let value = interp
+            .eval(b"class Raiser; def boom; raise ArgumentError, 'boom'; end; end; Raiser.new")
+            .unwrap();
+        let err = value.try_funcall(&mut interp, "boom", &[], None).unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+    }
+
+    #[test]
+    fn funcall_with_rust_closure_block() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let array = interp.eval(b"[1, 2, 3, 4]").unwrap();
+        let mut sum = 0;
+        let _ = array
+            .funcall_with_block(&mut interp, "each", &[], |interp, args| {
+                sum += args[0].try_into::<Int>(interp)?;
+                Ok(Value::nil())
+            })
+            .unwrap();
+        assert_eq!(10, sum);
+    }
+
+    #[test]
+    fn funcall_with_block_propagates_closure_errors() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let array = interp.eval(b"[1, 2, 3, 4]").unwrap();
+        let err = array
+            .funcall_with_block(&mut interp, "each", &[], |_interp, _args| {
+                Err(ArgumentError::from("boom").into())
+            })
+            .unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+        assert_eq!(&b"boom"[..], err.message().as_ref());
+    }
+
+    #[test]
+    fn funcall_with_block_closure_return_value_is_yield_result() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let array = interp.eval(b"[1, 2, 3]").unwrap();
+        let result = array
+            .funcall_with_block(&mut interp, "map", &[], |interp, args| {
+                let doubled = args[0].try_into::<Int>(interp)? * 2;
+                Ok(interp.convert(doubled))
+            })
+            .unwrap();
+        let result = result.try_into_mut::<Vec<Int>>(&mut interp).unwrap();
+        assert_eq!(vec![2, 4, 6], result);
+    }
+
+    #[test]
+    fn funcall_with_more_args_than_inline_max() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let value = interp
+            .eval(b"class Variadic; def collect(*args); args; end; end; Variadic.new")
+            .unwrap();
+        let args = (0..50_i64).map(|arg| interp.convert(arg)).collect::<Vec<_>>();
+        let result = value.funcall(&mut interp, "collect", &args, None).unwrap();
+        let result = result.try_into_mut::<Vec<Int>>(&mut interp).unwrap();
+        let expected = (0..50).collect::<Vec<Int>>();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn as_string_strict_accepts_strings() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let value = interp.eval(b"'artichoke'").unwrap();
+        let string = value.as_string_strict(&mut interp).unwrap();
+        assert_eq!(string, b"artichoke");
+    }
+
+    #[test]
+    fn as_string_strict_rejects_symbols() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let value = interp.eval(b":artichoke").unwrap();
+        let err = value.as_string_strict(&mut interp).unwrap_err();
+        assert_eq!(
+            &b"wrong argument type Symbol (expected String)"[..],
+            err.message().as_ref()
+        );
+    }
+
+    #[test]
+    fn as_string_strict_rejects_coercible_objects() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let value = interp
+            .eval(b"class Coercible; def to_str; 'artichoke'; end; end; Coercible.new")
+            .unwrap();
+        let err = value.as_string_strict(&mut interp).unwrap_err();
+        assert_eq!(
+            &b"wrong argument type Coercible (expected String)"[..],
+            err.message().as_ref()
+        );
+    }
+
+    #[test]
+    fn inspect_falls_back_to_default_representation() {
+        let mut interp = crate::interpreter().unwrap();
+
+        let value = interp
+            .eval(b"class BadInspect; def inspect; raise 'nope'; end; end; BadInspect.new")
+            .unwrap();
+        let debug = value.inspect(&mut interp);
+        assert!(debug.starts_with(b"#<BadInspect:0x"));
+        assert!(debug.ends_with(b">"));
+
+        let value = interp
+            .eval(b"class NonStringInspect; def inspect; 17; end; end; NonStringInspect.new")
+            .unwrap();
+        let debug = value.inspect(&mut interp);
+        assert!(debug.starts_with(b"#<NonStringInspect:0x"));
+    }
+
     #[test]
     fn to_s_false() {
         let mut interp = crate::interpreter().unwrap();
@@ -650,6 +1646,256 @@ mod tests {
         assert!(!fixnum.is_dead(&mut interp));
     }
 
+    #[test]
+    fn try_convert_to_symbol() {
+        let mut interp = crate::interpreter().unwrap();
+        let sym = interp.eval(b":artichoke").unwrap();
+        let sym = sym.try_convert_to_symbol(&mut interp).unwrap();
+        assert_eq!(sym.bytes(&mut interp), b"artichoke");
+
+        let string = interp.convert_mut("artichoke");
+        let from_string = string.try_convert_to_symbol(&mut interp).unwrap();
+        assert_eq!(from_string.bytes(&mut interp), b"artichoke");
+        assert_eq!(sym, from_string);
+
+        let fixnum = Convert::<_, Value>::convert(&interp, 255);
+        let err = fixnum.try_convert_to_symbol(&mut interp).unwrap_err();
+        assert_eq!(
+            &b"Fixnum is not a symbol nor a string"[..],
+            err.message().as_ref()
+        );
+    }
+
+    #[test]
+    fn funcall_symbol() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.convert_mut("upcased");
+        let sym = value
+            .funcall_symbol(&mut interp, "to_sym", &[], None)
+            .unwrap();
+        assert_eq!(sym.bytes(&mut interp), b"upcased");
+    }
+
+    #[test]
+    fn tap_returns_same_value() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.convert_mut("artichoke");
+        let tapped = value
+            .tap(&mut interp, |value, interp| {
+                let mut value = *value;
+                value.freeze(interp)
+            })
+            .unwrap();
+        assert_eq!(value, tapped);
+        assert!(tapped.is_frozen(&mut interp));
+    }
+
+    #[test]
+    fn tap_propagates_errors() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.convert_mut("artichoke");
+        let err = value
+            .tap(&mut interp, |value, interp| {
+                value
+                    .funcall(interp, "garbage_method_name", &[], None)
+                    .map(drop)
+            })
+            .unwrap_err();
+        assert_eq!("NoMethodError", err.name().as_ref());
+    }
+
+    #[test]
+    fn convert_into_immutable_and_mutable_targets() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.eval(b"255").unwrap();
+        let int = value.convert_into::<Int>(&mut interp).unwrap();
+        assert_eq!(int, 255);
+        let value = interp.convert_mut("artichoke");
+        let bytes = value.convert_into::<Vec<u8>>(&mut interp).unwrap();
+        assert_eq!(bytes, b"artichoke".to_vec());
+    }
+
+    #[test]
+    fn convert_into_propagates_conversion_errors() {
+        let mut interp = crate::interpreter().unwrap();
+        let value = interp.convert_mut("artichoke");
+        assert!(value.convert_into::<Int>(&mut interp).is_err());
+    }
+
+    #[test]
+    fn deep_eq_nested_structures() {
+        let mut interp = crate::interpreter().unwrap();
+        let left = interp
+            .eval(b"{ 'a' => [1, 2, { b: :c }], 'd' => nil }")
+            .unwrap();
+        let right = interp
+            .eval(b"{ 'd' => nil, 'a' => [1, 2, { b: :c }] }")
+            .unwrap();
+        assert!(left.deep_eq(&mut interp, &right).unwrap());
+        assert!(right.deep_eq(&mut interp, &left).unwrap());
+    }
+
+    #[test]
+    fn deep_eq_detects_differences() {
+        let mut interp = crate::interpreter().unwrap();
+        let left = interp.eval(b"{ 'a' => [1, 2, 3] }").unwrap();
+        let right = interp.eval(b"{ 'a' => [1, 2, 4] }").unwrap();
+        assert!(!left.deep_eq(&mut interp, &right).unwrap());
+        let shorter = interp.eval(b"{ 'a' => [1, 2] }").unwrap();
+        assert!(!left.deep_eq(&mut interp, &shorter).unwrap());
+    }
+
+    #[test]
+    fn deep_eq_cyclic_structures() {
+        let mut interp = crate::interpreter().unwrap();
+        let left = interp.eval(b"left = [1]; left << left; left").unwrap();
+        let right = interp.eval(b"right = [1]; right << right; right").unwrap();
+        assert!(left.deep_eq(&mut interp, &right).unwrap());
+    }
+
+    #[test]
+    fn deep_dup_copies_nested_containers() {
+        let mut interp = crate::interpreter().unwrap();
+        let original = interp
+            .eval(b"$config = { 'name' => 'artichoke', 'flags' => [1, [2, 3]] }")
+            .unwrap();
+        let copy = original.deep_dup(&mut interp).unwrap();
+        assert!(original.deep_eq(&mut interp, &copy).unwrap());
+
+        // Mutating the copy must not be observable through the original.
+        let name_key = interp.convert_mut("name");
+        let changed = interp.convert_mut("changed");
+        let _ = copy
+            .funcall(&mut interp, "[]=", &[name_key, changed], None)
+            .unwrap();
+        let flags_key = interp.convert_mut("flags");
+        let flags = copy.funcall(&mut interp, "[]", &[flags_key], None).unwrap();
+        let four = interp.convert(4);
+        let _ = flags.funcall(&mut interp, "push", &[four], None).unwrap();
+        let unchanged = interp
+            .eval(b"$config == { 'name' => 'artichoke', 'flags' => [1, [2, 3]] }")
+            .unwrap();
+        assert!(unchanged.try_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn deep_dup_detects_cycles() {
+        let mut interp = crate::interpreter().unwrap();
+        let cyclic = interp.eval(b"cyclic = [1]; cyclic << cyclic; cyclic").unwrap();
+        let err = cyclic.deep_dup(&mut interp).unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+    }
+
+    #[test]
+    fn describe_reports_fields_for_a_frozen_string() {
+        let mut interp = crate::interpreter().unwrap();
+        let string = interp.eval(b"'artichoke'.freeze").unwrap();
+        let info = string.describe(&mut interp).unwrap();
+        assert_eq!(Ruby::String, info.ruby_type);
+        assert_eq!("String", info.class_name);
+        assert!(info.frozen);
+        let object_id = string
+            .funcall(&mut interp, "object_id", &[], None)
+            .and_then(|id| id.try_into::<Int>(&interp))
+            .unwrap();
+        assert_eq!(object_id, info.object_id);
+        assert_eq!(&b"\"artichoke\""[..], info.inspect.as_slice());
+    }
+
+    #[test]
+    fn describe_truncates_long_inspect_output() {
+        let mut interp = crate::interpreter().unwrap();
+        let string = interp.eval(b"'a' * 500").unwrap();
+        let info = string.describe(&mut interp).unwrap();
+        assert!(!info.frozen);
+        assert_eq!(Value::INSPECT_TRUNCATE_LEN + 3, info.inspect.len());
+        assert!(info.inspect.ends_with(b"..."));
+    }
+
+    #[test]
+    fn each_yields_array_elements() {
+        let mut interp = crate::interpreter().unwrap();
+        let ary = interp.eval(b"[1, 2, 3]").unwrap();
+        let mut sum = 0;
+        ary.each(&mut interp, |interp, elem| {
+            sum += elem.try_into::<Int>(interp)?;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(6, sum);
+    }
+
+    #[test]
+    fn each_yields_custom_enumerable_elements() {
+        let mut interp = crate::interpreter().unwrap();
+        let code = br#"
+class Countdown
+  include Enumerable
+
+  def each
+    yield 3
+    yield 2
+    yield 1
+  end
+end
+Countdown.new
+"#;
+        let enumerable = interp.eval(code).unwrap();
+        let mut seen = Vec::new();
+        enumerable
+            .each(&mut interp, |interp, elem| {
+                seen.push(elem.try_into::<Int>(interp)?);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(vec![3, 2, 1], seen);
+    }
+
+    #[test]
+    fn each_propagates_closure_errors() {
+        let mut interp = crate::interpreter().unwrap();
+        let ary = interp.eval(b"[1, 2, 3]").unwrap();
+        let err = ary
+            .each(&mut interp, |_, _| Err(ArgumentError::from("stop").into()))
+            .unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+        let object = interp.eval(b"Object.new").unwrap();
+        let err = object.each(&mut interp, |_, _| Ok(())).unwrap_err();
+        assert_eq!("NoMethodError", err.name().as_ref());
+    }
+
+    #[test]
+    fn instance_variable_get_accepts_string_and_symbol_names() {
+        let mut interp = crate::interpreter().unwrap();
+        let obj = interp
+            .eval(b"class IvarHolder; def initialize; @x = 7; end; end; IvarHolder.new")
+            .unwrap();
+        let by_rust = obj.instance_variable(&mut interp, "@x").unwrap();
+        assert_eq!(7, by_rust.try_into::<Int>(&interp).unwrap());
+        let by_string = interp
+            .eval(b"IvarHolder.new.instance_variable_get('@x')")
+            .unwrap();
+        assert_eq!(7, by_string.try_into::<Int>(&interp).unwrap());
+        let by_symbol = interp
+            .eval(b"IvarHolder.new.instance_variable_get(:@x)")
+            .unwrap();
+        assert_eq!(7, by_symbol.try_into::<Int>(&interp).unwrap());
+        let unset = obj.instance_variable(&mut interp, "@unset").unwrap();
+        assert!(unset.is_nil());
+    }
+
+    #[test]
+    fn instance_variable_get_rejects_names_without_at_sign() {
+        let mut interp = crate::interpreter().unwrap();
+        let obj = interp.eval(b"Object.new").unwrap();
+        let err = obj.instance_variable(&mut interp, "x").unwrap_err();
+        assert_eq!("NameError", err.name().as_ref());
+        let err = interp
+            .eval(b"Object.new.instance_variable_get(:x)")
+            .unwrap_err();
+        assert_eq!("NameError", err.name().as_ref());
+    }
+
     #[test]
     fn funcall() {
         let mut interp = crate::interpreter().unwrap();
@@ -713,4 +1959,88 @@ mod tests {
             err.message().as_ref()
         );
     }
+
+    #[test]
+    fn try_to_a_on_arrays_and_ranges() {
+        let mut interp = crate::interpreter().unwrap();
+        let ary = interp.eval(b"[1, 2, 3]").unwrap();
+        let ary = ary.try_to_a(&mut interp).unwrap();
+        assert_eq!(3, ary.len());
+        let range = interp.eval(b"(1..3)").unwrap();
+        let range = range.try_to_a(&mut interp).unwrap();
+        let range = range
+            .iter()
+            .map(|elem| elem.try_into::<Int>(&interp).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3], range);
+    }
+
+    #[test]
+    fn try_to_a_errors_on_objects_without_to_a() {
+        let mut interp = crate::interpreter().unwrap();
+        let obj = interp.eval(b"Object.new").unwrap();
+        let err = obj.try_to_a(&mut interp).unwrap_err();
+        assert_eq!("NoMethodError", err.name().as_ref());
+    }
+
+    #[test]
+    fn try_to_a_errors_on_non_array_to_a() {
+        let mut interp = crate::interpreter().unwrap();
+        let obj = interp
+            .eval(b"class BadToA; def to_a; 'oops'; end; end; BadToA.new")
+            .unwrap();
+        let err = obj.try_to_a(&mut interp).unwrap_err();
+        assert_eq!("TypeError", err.name().as_ref());
+        assert_eq!(
+            &b"can't convert BadToA to Array (BadToA#to_a gives String)"[..],
+            err.message().as_ref()
+        );
+    }
+
+    #[test]
+    fn dup_copies_the_ruby_object() {
+        let mut interp = crate::interpreter().unwrap();
+        let original = interp.eval(b"'artichoke'").unwrap();
+        let duped = original.dup(&mut interp).unwrap();
+        let suffix = interp.convert_mut(" ruby");
+        let _ = duped
+            .funcall(&mut interp, "concat", &[suffix], None)
+            .unwrap();
+        let duped = duped.try_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!("artichoke ruby", duped);
+        // Mutating the dup leaves the original untouched.
+        let original = original.try_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!("artichoke", original);
+    }
+
+    #[test]
+    fn clone_ruby_preserves_frozen_state() {
+        let mut interp = crate::interpreter().unwrap();
+        let original = interp.eval(b"'artichoke'.freeze").unwrap();
+        let cloned = original.clone_ruby(&mut interp).unwrap();
+        assert!(cloned.is_frozen(&mut interp));
+        let duped = original.dup(&mut interp).unwrap();
+        assert!(!duped.is_frozen(&mut interp));
+    }
+
+    #[test]
+    fn as_bool_or_nil_is_tri_state() {
+        let mut interp = crate::interpreter().unwrap();
+        assert_eq!(Some(true), interp.eval(b"true").unwrap().as_bool_or_nil());
+        assert_eq!(Some(false), interp.eval(b"false").unwrap().as_bool_or_nil());
+        assert_eq!(None, interp.eval(b"nil").unwrap().as_bool_or_nil());
+        // Every non-`nil`, non-`false` value is truthy, including zero and the
+        // empty string.
+        assert_eq!(Some(true), interp.eval(b"0").unwrap().as_bool_or_nil());
+        assert_eq!(Some(true), interp.eval(b"''").unwrap().as_bool_or_nil());
+    }
+
+    #[test]
+    fn is_truthy_collapses_nil_into_false() {
+        let mut interp = crate::interpreter().unwrap();
+        assert!(interp.eval(b"true").unwrap().is_truthy());
+        assert!(interp.eval(b"Object.new").unwrap().is_truthy());
+        assert!(!interp.eval(b"false").unwrap().is_truthy());
+        assert!(!interp.eval(b"nil").unwrap().is_truthy());
+    }
 }
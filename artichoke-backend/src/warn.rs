@@ -37,4 +37,38 @@ impl Warn for Artichoke {
         let _ = warning.funcall(self, "warn", &[message], None)?;
         Ok(())
     }
+
+    fn warn_once(&mut self, message: &[u8]) -> Result<bool, Self::Error> {
+        let state = self.state.as_ref().ok_or(InterpreterExtractError)?;
+        if state.warned_messages.contains(message) {
+            return Ok(false);
+        }
+        self.warn(message)?;
+        let state = self.state.as_mut().ok_or(InterpreterExtractError)?;
+        state.warned_messages.insert(message.to_vec());
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn warn_once_suppresses_duplicates() {
+        let mut interp = crate::interpreter().unwrap();
+        assert!(interp.warn_once(b"deprecated thing").unwrap());
+        assert!(!interp.warn_once(b"deprecated thing").unwrap());
+        // Distinct messages are never collapsed.
+        assert!(interp.warn_once(b"other thing").unwrap());
+        assert!(!interp.warn_once(b"other thing").unwrap());
+    }
+
+    #[test]
+    fn warn_once_tracking_is_per_interpreter() {
+        let mut interp = crate::interpreter().unwrap();
+        assert!(interp.warn_once(b"deprecated thing").unwrap());
+        let mut interp = crate::interpreter().unwrap();
+        assert!(interp.warn_once(b"deprecated thing").unwrap());
+    }
 }
@@ -36,6 +36,33 @@ pub trait Intern {
     where
         T: Into<Cow<'static, [u8]>>;
 
+    /// Store a sequence of immutable bytestrings for the life of the
+    /// interpreter.
+    ///
+    /// Returns the symbol identifiers in input order. Names that are already
+    /// interned resolve to their existing identifiers.
+    ///
+    /// By default, this method is implemented by delegating to
+    /// [`Intern::intern_bytes`] for each name. Implementations may override
+    /// this method to reserve capacity in the underlying storage up front.
+    ///
+    /// # Errors
+    ///
+    /// If the symbol store cannot be accessed, an error is returned.
+    ///
+    /// If the symbol table overflows, an error is returned.
+    fn intern_all<I>(&mut self, symbols: I) -> Result<Vec<Self::Symbol>, Self::Error>
+    where
+        I: IntoIterator<Item = Vec<u8>>,
+    {
+        let symbols = symbols.into_iter();
+        let mut all = Vec::with_capacity(symbols.size_hint().0);
+        for symbol in symbols {
+            all.push(self.intern_bytes(symbol)?);
+        }
+        Ok(all)
+    }
+
     /// Check if a bytestring is already interned and return its symbol
     /// identifier.  Return `None` if the string has not been interned before.
     ///
@@ -91,5 +91,5 @@ pub mod prelude {
     pub use crate::top_self::TopSelf;
     pub use crate::types::{Ruby, Rust};
     pub use crate::value::Value;
-    pub use crate::warn::Warn;
+    pub use crate::warn::{Warn, WarningCategory};
 }
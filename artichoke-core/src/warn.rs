@@ -2,6 +2,26 @@
 
 use std::error;
 
+/// Category of an emitted warning.
+///
+/// Ruby 3's `Warning.warn` accepts a `category:` keyword which implementers
+/// can use to suppress whole classes of warnings.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WarningCategory {
+    /// The warning concerns deprecated functionality slated for removal.
+    Deprecated,
+    /// The warning concerns experimental functionality that may change.
+    Experimental,
+    /// The warning does not belong to a specific category.
+    Unspecified,
+}
+
+impl Default for WarningCategory {
+    fn default() -> Self {
+        Self::Unspecified
+    }
+}
+
 /// Emit warnings during interpreter execution to stderr.
 ///
 /// Some functionality required to be compliant with ruby/spec is deprecated or
@@ -24,4 +44,45 @@ pub trait Warn {
     ///
     /// If an exception is raised on the interpreter, then an error is returned.
     fn warn(&mut self, message: &[u8]) -> Result<(), Self::Error>;
+
+    /// Emit a warning message with a [`WarningCategory`].
+    ///
+    /// The default implementation discards the category and forwards to
+    /// [`Warn::warn`]. Implementers may override this method to suppress
+    /// certain categories.
+    ///
+    /// `Kernel#warn`'s `uplevel:` keyword is not part of this trait; prefixing
+    /// the message with caller source location happens in the kernel layer
+    /// before the message reaches `Warn`.
+    ///
+    /// # Errors
+    ///
+    /// If an exception is raised on the interpreter, then an error is returned.
+    fn warn_with_category(
+        &mut self,
+        message: &[u8],
+        category: WarningCategory,
+    ) -> Result<(), Self::Error> {
+        let _ = category;
+        self.warn(message)
+    }
+
+    /// Emit a warning message unless an identical message was already emitted.
+    ///
+    /// Returns `true` if the message was emitted and `false` if it was
+    /// suppressed as a duplicate. Long-running scripts use this to avoid
+    /// repeating the same deprecation warning on every call.
+    ///
+    /// The default implementation performs no de-duplication and always emits
+    /// the message. Implementers that track emitted messages should scope the
+    /// tracking to a single interpreter so distinct messages are never
+    /// collapsed and fresh interpreters start with a clean slate.
+    ///
+    /// # Errors
+    ///
+    /// If an exception is raised on the interpreter, then an error is returned.
+    fn warn_once(&mut self, message: &[u8]) -> Result<bool, Self::Error> {
+        self.warn(message)?;
+        Ok(true)
+    }
 }